@@ -14,6 +14,13 @@ mod ballot {
     struct Proposal {
         name: String,
         vote_count: u32, 
+        description: String,
+        /// votes needed for the proposal to be approved, 0 if it has none
+        threshold: u32,
+        /// link to the off-chain content of the proposal, empty if it has none
+        uri: String,
+        /// whether votes for the proposal are accepted
+        active: bool,
     }
 
     // Structure to store Proposal information
@@ -22,9 +29,237 @@ mod ballot {
         weight: u32,
         voted: bool,
         delegate: Option<AccountId>, 
-        vote: Option<i32>, 
+        vote: Option<u32>, 
+        /// hash of the hidden vote committed with `commit_vote`
+        commitment: Option<[u8; 32]>,
+        /// block timestamp at which the vote was cast
+        voted_at: Option<Timestamp>,
+        /// weight received from voters who delegated to this voter,
+        /// it is part of `weight` so that it follows a changed vote
+        delegated_weight: u32,
+        /// last block in which the voting right can be used, if limited
+        rights_expiry: Option<BlockNumber>,
+        /// block in which the voter was registered
+        registered_at: BlockNumber,
     }
 
+    impl Voter {
+        /// returns whether the voter may cast their weight at `block`,
+        /// voters registered after the `snapshot` block may not
+        fn has_right_at(&self, block: BlockNumber, snapshot: Option<BlockNumber>) -> bool {
+            self.weight > 0
+                && self.rights_expiry.is_none_or(|expiry| block <= expiry)
+                && snapshot.is_none_or(|snapshot| self.registered_at <= snapshot)
+        }
+
+        /// gives the voter an unlimited right to vote with `weight` of
+        /// their own, votes delegated to them are kept
+        fn grant(&mut self, weight: u32) -> Result<()> {
+            self.weight = weight.checked_add(self.delegated_weight).ok_or(Error::Overflow)?;
+            self.rights_expiry = None;
+            Ok(())
+        }
+    }
+
+    /// Public view on a voter returned by the voter getters.
+    #[derive(Clone, Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct VoterInfo {
+        pub weight: u32,
+        pub voted: bool,
+        pub delegate: Option<AccountId>,
+        pub vote: Option<u32>,
+        pub voted_at: Option<Timestamp>,
+        pub delegated_weight: u32,
+    }
+
+    impl From<&Voter> for VoterInfo {
+        fn from(voter: &Voter) -> Self {
+            Self {
+                weight: voter.weight,
+                voted: voter.voted,
+                delegate: voter.delegate,
+                vote: voter.vote,
+                voted_at: voter.voted_at,
+                delegated_weight: voter.delegated_weight,
+            }
+        }
+    }
+
+    /// How the votes of a ballot are tallied.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout,scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink_storage::traits::StorageLayout))]
+    pub enum VotingMode {
+        /// every voter picks a single proposal with `vote`
+        Plurality,
+        /// every voter ranks proposals with `vote_ranked`, the winner is
+        /// found with instant runoff by `compute_irv_winner`
+        RankedChoice,
+    }
+
+    /// Rules of a ballot as returned by `config`.
+    #[derive(Clone, Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct BallotConfig {
+        pub quorum: u32,
+        pub deadline: Option<BlockNumber>,
+        pub start_block: Option<BlockNumber>,
+        pub max_proposals: u32,
+        pub chair_can_vote: bool,
+        pub tie_break: TieBreak,
+    }
+
+    /// Outcome of a ballot and whether it is valid, as returned by `final_result`.
+    #[derive(Clone, Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct FinalResult {
+        pub winner_index: Option<u32>,
+        pub winner_name: Option<String>,
+        /// vote count of the winner, the highest vote count without one
+        pub winner_votes: u32,
+        pub quorum_met: bool,
+        /// whether several proposals share the highest vote count
+        pub is_tie: bool,
+    }
+
+    /// How the winner is picked when several proposals share the most votes.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, scale::Encode, scale::Decode, SpreadLayout, PackedLayout,scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(ink_storage::traits::StorageLayout))]
+    pub enum TieBreak {
+        /// the proposal added first wins
+        LowestIndex,
+        /// the proposal added last wins
+        HighestIndex,
+        /// a tie has no winner
+        None,
+    }
+
+    /// Errors that can occur upon calling this contract.
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Returned if the caller is not a registered voter.
+        NotAVoter,
+        /// Returned if the voter has already voted or delegated.
+        AlreadyVoted,
+        /// Returned if the voter has not been given the right to vote.
+        NoVotingRight,
+        /// Returned if the proposal index does not point to a proposal.
+        ProposalIndexOutOfBounds,
+        /// Returned if the voting deadline has already passed.
+        VotingClosed,
+        /// Returned if the voter has not cast a vote yet.
+        HasNotVoted,
+        /// Returned if the voter delegated their vote instead of voting.
+        VoteWasDelegated,
+        /// Returned if the delegation chain would lead back to the caller.
+        DelegationLoop,
+        /// Returned if a proposal that already received votes is removed.
+        ProposalHasVotes,
+        /// Returned if proposals are added after they have been locked.
+        ProposalsLocked,
+        /// Returned if a proposal with the same name already exists.
+        DuplicateProposal,
+        /// Returned if votes are revealed before the deadline has passed.
+        VotingNotClosed,
+        /// Returned if the voter has not committed a vote to reveal.
+        NoCommitment,
+        /// Returned if the revealed vote does not match the commitment.
+        CommitmentMismatch,
+        /// Returned if the ballot is paused by the chairperson.
+        Paused,
+        /// Returned if the voter has not delegated their vote.
+        NotDelegated,
+        /// Returned if a proposal name is empty or too long.
+        ProposalNameInvalid,
+        /// Returned if voting has not started yet.
+        NotStarted,
+        /// Returned if a voter with voting rights or votes is removed.
+        CannotRemoveActiveVoter,
+        /// Returned if the chairperson votes on a ballot that forbids it.
+        ChairCannotVote,
+        /// Returned if a delegation is cleared after the delegate voted.
+        DelegateAlreadyVoted,
+        /// Returned if a voter is added by someone other than the
+        /// chairperson while registration is closed.
+        RegistrationClosed,
+        /// Returned if a voter delegates their vote to themselves.
+        SelfDelegation,
+        /// Returned if the vote is delegated to an account that is not a voter.
+        DelegateNotFound,
+        /// Returned if the ballot has already been finalized.
+        AlreadyFinalized,
+        /// Returned if votes are cast before enough proposals were added.
+        NotEnoughProposals,
+        /// Returned if a vote does not match the voting mode of the ballot.
+        WrongVotingMode,
+        /// Returned if a ranking is empty or lists a proposal twice.
+        InvalidRanking,
+        /// Returned if adding a weight would overflow a vote count.
+        Overflow,
+        /// Returned if the deadline would not be moved to a later block.
+        DeadlineCannotShrink,
+        /// Returned if a proposal is merged into itself.
+        SameProposal,
+        /// Returned if a proposal is added with an empty URI.
+        ProposalUriInvalid,
+        /// Returned if a stored vote does not point to a proposal anymore.
+        StaleVoteIndex,
+        /// Returned if a proposal is added once the maximum is reached.
+        TooManyProposals,
+        /// Returned if votes are seeded once voting has begun.
+        SeedingClosed,
+        /// Returned if a display order is not a permutation of the proposals.
+        InvalidDisplayOrder,
+        /// Returned if a delegation would raise the weight of the delegate
+        /// above `max_delegated_weight`.
+        DelegationCapExceeded,
+        /// Returned if a vote is cast for a proposal that is disabled.
+        ProposalInactive,
+        /// Returned if a proposal is written in by a voter whose weight is
+        /// below `min_weight_to_propose`.
+        InsufficientWeight,
+        /// Returned if a delegation has to be forwarded more often than
+        /// `max_delegation_depth` allows.
+        DelegationTooDeep,
+        /// Returned if the right to vote is taken from a voter who holds
+        /// votes delegated to them.
+        HoldsDelegatedVotes,
+        /// Returned if proposals are removed or merged while committed
+        /// votes have not been revealed.
+        CommitmentsPending,
+        /// Returned if the deadline is extended on a ballot without one.
+        NoDeadline,
+        /// Returned if a deadline is set on a ballot that already has one,
+        /// it can only be moved with `extend_deadline`.
+        DeadlineAlreadySet,
+        /// Returned if the deadline would be set to a block that has passed.
+        DeadlineInPast,
+    }
+
+    /// Read-only view on the results of a ballot, so that other
+    /// contracts can query them through a typed interface.
+    #[ink_lang::trait_definition]
+    pub trait BallotReader {
+        /// returns the index of the proposal with the most votes,
+        /// or `None` if no proposal has received a vote yet
+        #[ink(message)]
+        fn winning_proposal_index(&self) -> Option<u32>;
+
+        /// given an index returns the vote count of the proposal at that index
+        #[ink(message)]
+        fn get_proposal_votes(&self, index: u32) -> Option<u32>;
+    }
+
+    /// Type alias for the contract's result type.
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// Maximum length in bytes of a proposal name used by the default constructor.
+    pub const DEFAULT_MAX_NAME_LEN: u32 = 64;
+
+    /// Minimum number of proposals before voting starts used by the default constructor.
+    pub const DEFAULT_MIN_PROPOSALS: u32 = 2;
+
     /// Defines the storage of your contract.
     /// Add new fields to the below struct in order
     /// to add new static storage fields to your contract.
@@ -32,12 +267,178 @@ mod ballot {
     pub struct Ballot {
         chair_person: AccountId,
         voters: HashMap<AccountId, Voter>,
-        proposals: Vec<Proposal>    
+        proposals: Vec<Proposal>,
+        /// first block in which votes are accepted
+        start_block: Option<BlockNumber>,
+        /// last block in which votes are still accepted
+        deadline: Option<BlockNumber>,
+        /// sum of the vote counts of all proposals
+        total_votes: u32,
+        /// once set, no more proposals can be added
+        proposals_locked: bool,
+        /// minimum number of votes for the result to be valid
+        quorum: u32,
+        /// while set, voters and voting rights cannot change
+        paused: bool,
+        /// maximum length in bytes of a proposal name
+        max_name_len: u32,
+        /// whether the chair person may vote or delegate
+        chair_can_vote: bool,
+        /// whether anyone may add voters or only the chair person
+        registration_open: bool,
+        /// set once the chair person declared the outcome with `finalize`
+        finalized: bool,
+        /// index of the proposal declared the winner by `finalize`
+        finalized_winner: Option<u32>,
+        /// number of proposals required before votes can be cast
+        min_proposals: u32,
+        /// whether voters pick one proposal or rank them
+        voting_mode: VotingMode,
+        /// proposal indices in order of preference of each ranked voter
+        rankings: HashMap<AccountId, Vec<u32>>,
+        /// previous chair persons, oldest first
+        chair_history: Vec<AccountId>,
+        /// how the winner is picked among tied proposals
+        tie_break: TieBreak,
+        /// maximum number of proposals that can be added, 0 if unlimited
+        max_proposals: u32,
+        /// index of the proposal currently leading, kept up to date as
+        /// votes come in so that the winner does not need a full scan
+        leading_index: Option<u32>,
+        /// vote count of the leading proposal
+        leading_votes: u32,
+        /// set once the first vote has been cast, seeding is closed then
+        has_live_votes: bool,
+        /// proposal indices in the order they should be displayed,
+        /// empty for index order
+        display_order: Vec<u32>,
+        /// maximum weight a voter can reach through delegations, 0 if unlimited
+        max_delegated_weight: u32,
+        /// short name of the ballot shown to voters
+        title: String,
+        /// what the ballot is about
+        description: String,
+        /// whether the ballot is finalized as soon as a vote meets the quorum
+        auto_finalize: bool,
+        /// weight a voter needs to write in a proposal, the chair person
+        /// is exempt
+        min_weight_to_propose: u32,
+        /// number of times a delegation may be forwarded, 0 if unlimited
+        max_delegation_depth: u32,
+        /// voters registered after this block may not vote, if set
+        snapshot_block: Option<BlockNumber>,
+        /// summed weight of the voters who cast a blank vote
+        blank_votes: u32,
+    }
+
+    /// Event emitted when a voter casts their vote.
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        voter: AccountId,
+        proposal_index: u32,
+        weight: u32,
+    }
+
+    /// Event emitted when a voter casts a blank vote.
+    #[ink(event)]
+    pub struct BlankVoteCast {
+        #[ink(topic)]
+        voter: AccountId,
+        weight: u32,
+    }
+
+    /// Event emitted when a new voter is registered.
+    #[ink(event)]
+    pub struct VoterAdded {
+        #[ink(topic)]
+        voter: AccountId,
+    }
+
+    /// Event emitted when a voter is given the right to vote.
+    #[ink(event)]
+    pub struct VotingRightGranted {
+        #[ink(topic)]
+        voter: AccountId,
+    }
+
+    /// Event emitted when the right to vote is taken from a voter.
+    #[ink(event)]
+    pub struct VotingRightRevoked {
+        #[ink(topic)]
+        voter: AccountId,
+    }
+
+    /// Event emitted when the chairperson role changes hands.
+    #[ink(event)]
+    pub struct ChairpersonTransferred {
+        #[ink(topic)]
+        old_chair: AccountId,
+        #[ink(topic)]
+        new_chair: AccountId,
+    }
+
+    /// Event emitted when a voter delegates their vote.
+    #[ink(event)]
+    pub struct Delegated {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        weight: u32,
+    }
+
+    /// Event emitted when the ballot is reset for a new round.
+    #[ink(event)]
+    pub struct BallotReset {}
+
+    /// Event emitted when a proposal is added to the ballot.
+    #[ink(event)]
+    pub struct ProposalAdded {
+        index: u32,
+        name: String,
+    }
+
+    /// Event emitted when the chairperson corrects the name of a proposal.
+    #[ink(event)]
+    pub struct ProposalRenamed {
+        index: u32,
+        old_name: String,
+        new_name: String,
+    }
+
+    /// Event emitted when the chairperson seeds votes from a prior tally.
+    #[ink(event)]
+    pub struct VotesSeeded {
+        index: u32,
+        count: u32,
+    }
+
+    /// Event emitted when the chairperson finalizes the ballot.
+    #[ink(event)]
+    pub struct WinnerDeclared {
+        index: u32,
+        name: String,
+        votes: u32,
     }
 
     impl Ballot {
         #[ink(constructor)]
-        pub fn new(proposal_names: Option<Vec<String>> ) -> Self {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            proposal_names: Option<Vec<String>>,
+            start_block: Option<BlockNumber>,
+            deadline: Option<BlockNumber>,
+            quorum: u32,
+            max_name_len: u32,
+            chair_can_vote: bool,
+            min_proposals: u32,
+            voting_mode: VotingMode,
+            tie_break: TieBreak,
+            max_proposals: u32,
+            title: String,
+            description: String,
+        ) -> Self {
 
             // get chair person address
             let chair_person =  Self::env().caller();
@@ -46,12 +447,18 @@ mod ballot {
             let mut proposals: Vec<Proposal> = Vec::new();
             let mut voters = HashMap::new();
 
-            // initialize chair person's vote
+            // initialize chair person's vote, a chair person
+            // who may not vote gets no voting right
             voters.insert(chair_person, Voter{
-                weight:1,
+                weight: if chair_can_vote { 1 } else { 0 },
                 voted:false,
                 delegate: None,
                 vote: None,
+                commitment: None,
+                voted_at: None,
+                delegated_weight: 0,
+                rights_expiry: None,
+                registered_at: Self::env().block_number(),
             });
 
 
@@ -66,6 +473,10 @@ mod ballot {
                             Proposal{
                             name: String::from(name),
                             vote_count: 0,
+                            description: String::new(),
+                            threshold: 0,
+                            uri: String::new(),
+                            active: true,
                         });
                     }
                 }
@@ -74,308 +485,4817 @@ mod ballot {
                 chair_person,
                 voters,
                 proposals,
+                start_block,
+                deadline,
+                total_votes: 0,
+                proposals_locked: false,
+                quorum,
+                paused: false,
+                max_name_len,
+                chair_can_vote,
+                registration_open: true,
+                finalized: false,
+                finalized_winner: None,
+                min_proposals,
+                voting_mode,
+                rankings: HashMap::new(),
+                chair_history: Vec::new(),
+                tie_break,
+                max_proposals,
+                leading_index: None,
+                leading_votes: 0,
+                has_live_votes: false,
+                display_order: Vec::new(),
+                max_delegated_weight: 0,
+                title,
+                description,
+                auto_finalize: false,
+                min_weight_to_propose: 0,
+                max_delegation_depth: 0,
+                snapshot_block: None,
+                blank_votes: 0,
             }
         }
 
         /// default constrcutor
         #[ink(constructor)]
         pub fn default() -> Self {
-            Self::new(Default::default())
+            Self::new(Default::default(), None, None, 0, DEFAULT_MAX_NAME_LEN, true, DEFAULT_MIN_PROPOSALS, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new())
+        }
+
+        /// constructor creating the proposals from `(name, description)`
+        /// pairs, the other settings are those of `default`.
+        /// Proposal names must be unique.
+        #[ink(constructor)]
+        pub fn new_with_descriptions(proposals: Vec<(String, String)>) -> Self {
+            let mut ballot = Self::default();
+            for (name, description) in proposals {
+                assert!(!ballot.proposal_exists(name.clone()), "proposal names must be unique");
+                ballot.proposals.push(Proposal{
+                    name,
+                    vote_count: 0,
+                    description,
+                    threshold: 0,
+                    uri: String::new(),
+                    active: true,
+                });
+            }
+            ballot
+        }
+
+
+        /// returns the version of the contract code, so that front-ends
+        /// can tell which features are available
+        #[ink(message)]
+        pub fn contract_version(&self) -> String {
+            String::from(env!("CARGO_PKG_VERSION"))
         }
 
+        /// returns the rules the ballot was configured with
+        #[ink(message)]
+        pub fn config(&self) -> BallotConfig {
+            BallotConfig {
+                quorum: self.quorum,
+                deadline: self.deadline,
+                start_block: self.start_block,
+                max_proposals: self.max_proposals,
+                chair_can_vote: self.chair_can_vote,
+                tie_break: self.tie_break,
+            }
+        }
 
         #[ink(message)]
         pub fn get_chairperson(&self) -> AccountId {
             self.chair_person
         }
 
+        /// returns true if `who` is the chair person of the ballot
+        #[ink(message)]
+        pub fn is_chairperson(&self, who: AccountId) -> bool {
+            who == self.chair_person
+        }
+
+        /// Hands the chairperson role over to `new_chair`.
+        /// Should only be called by `chairperson`. If `new_chair` is not
+        /// a voter yet, they are registered without voting right.
+        #[ink(message)]
+        pub fn transfer_chairperson(&mut self, new_chair: AccountId) {
+            let caller = self.env().caller();
+            // only chair person can transfer the role
+            assert_eq!(caller,self.chair_person, "only chair person can transfer the role");
 
+            if self.voters.get(&new_chair).is_none() {
+                self.voters.insert(new_chair, Voter{
+                    weight:0,
+                    voted:false,
+                    delegate: None,
+                    vote: None,
+                    commitment: None,
+                    voted_at: None,
+                    delegated_weight: 0,
+                    rights_expiry: None,
+                    registered_at: self.env().block_number(),
+                });
+            }
 
-        pub fn get_voter(&self, voter_id: AccountId) -> Option<&Voter>{
-            self.voters.get(&voter_id)
+            self.chair_history.push(caller);
+            self.chair_person = new_chair;
+            self.env().emit_event(ChairpersonTransferred {
+                old_chair: caller,
+                new_chair,
+            });
         }
 
-        pub fn get_voter_count(&self) -> usize{
-            self.voters.len() as usize
+        /// returns the previous chair persons in the order they held the role
+        #[ink(message)]
+        pub fn chairperson_history(&self) -> Vec<AccountId> {
+            self.chair_history.clone()
         }
 
-                /// the function adds the provided voter id into possible
-        /// list of voters. By default the voter has no voting right,
-        /// the contract owner must approve the voter before he can cast a vote
+        /// Sets the last block in which votes are accepted on a ballot
+        /// without deadline. Once set, it can only be moved to a later
+        /// block with `extend_deadline`.
+        /// Should only be called by `chairperson`.
         #[ink(message)]
-        pub fn add_voter(&mut self, voter_id: AccountId) -> bool{
+        pub fn set_deadline(&mut self, block: BlockNumber) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can set the deadline
+            assert_eq!(caller,self.chair_person, "only chair person can set the deadline");
 
-            let voter_opt = self.voters.get(&voter_id);
-            // the voter does not exists
-            if voter_opt.is_some() {
-                return false
+            if self.deadline.is_some() {
+                return Err(Error::DeadlineAlreadySet)
             }
-
-            self.voters.insert(voter_id, Voter{
-                weight:0,
-                voted:false,
-                delegate: None,
-                vote: None,
-            });
-            return true
+            // closing the voting right away is not allowed
+            if block < self.env().block_number() {
+                return Err(Error::DeadlineInPast)
+            }
+            self.deadline = Some(block);
+            Ok(())
         }
 
+        /// Gives voters more time by moving the deadline to a later block.
+        /// A ballot without deadline is already open indefinitely.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn extend_deadline(&mut self, new_deadline: BlockNumber) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can extend the deadline
+            assert_eq!(caller,self.chair_person, "only chair person can extend the deadline");
 
+            let deadline = match self.deadline {
+                Some(deadline) => deadline,
+                None => return Err(Error::NoDeadline),
+            };
+            // cutting the voting short is not allowed
+            if new_deadline <= deadline {
+                return Err(Error::DeadlineCannotShrink)
+            }
+            if new_deadline < self.env().block_number() {
+                return Err(Error::DeadlineInPast)
+            }
+            self.deadline = Some(new_deadline);
+            Ok(())
+        }
 
-        /// given an index returns the name of the proposal at that index
-        pub fn get_proposal_name_at_index(&self, index:usize) -> &String {
-            let proposal = self.proposals.get(index).unwrap();
-            return &proposal.name
+        /// returns the deadline block of the ballot, if any
+        #[ink(message)]
+        pub fn get_deadline(&self) -> Option<BlockNumber> {
+            self.deadline
         }
 
-        /// returns the number of proposals in ballet
-        pub fn get_proposal_count(&self) -> usize {
-            return self.proposals.len()
+        /// Halts all voting and voter changes until `unpause` is called.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn pause(&mut self) {
+            let caller = self.env().caller();
+            // only chair person can pause the ballot
+            assert_eq!(caller,self.chair_person, "only chair person can pause the ballot");
+            self.paused = true;
         }
 
-        /// adds the given proposal name in ballet
-        /// to do: check unqiueness of proposal,
-        pub fn add_proposal(&mut self, proposal_name: String){
-            self.proposals.push(
-                Proposal{
-                    name:String::from(proposal_name),
-                    vote_count: 0,
-            });
+        /// Resumes a ballot halted with `pause`.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn unpause(&mut self) {
+            let caller = self.env().caller();
+            // only chair person can unpause the ballot
+            assert_eq!(caller,self.chair_person, "only chair person can unpause the ballot");
+            self.paused = false;
         }
-        
-        /// Give `voter` the right to vote on this ballot.
+
+        /// Allows anyone to add voters with `add_voter`.
         /// Should only be called by `chairperson`.
         #[ink(message)]
-        pub fn give_voting_right(&mut self, voter_id: AccountId) {
+        pub fn open_registration(&mut self) {
             let caller = self.env().caller();
-            let voter_opt = self.voters.get_mut(&voter_id);
+            // only chair person can open the registration
+            assert_eq!(caller,self.chair_person, "only chair person can open the registration");
+            self.registration_open = true;
+        }
 
-            // ACTION: check if the caller is the chair_person
-            //         * check if the voter_id exists in ballot
-            //         * check if voter has not already voted
-            //         * if everything alright update voters weight to 1
-    
+        /// Restricts `add_voter` to the chair person.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn close_registration(&mut self) {
+            let caller = self.env().caller();
+            // only chair person can close the registration
+            assert_eq!(caller,self.chair_person, "only chair person can close the registration");
+            self.registration_open = false;
+        }
 
-            // only chair person can give right to vote
-            assert_eq!(caller,self.chair_person, "only chair person can give right to vote");
+        /// returns whether anyone may add voters
+        #[ink(message)]
+        pub fn is_registration_open(&self) -> bool {
+            self.registration_open
+        }
 
-            // the voter does not exists
-            assert_eq!(voter_opt.is_some(),true, "provided voterId does not exist");
+        /// returns whether the ballot is paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
 
-            let voter = voter_opt.unwrap();
-            // the voter should not have already voted
-            assert_eq!(voter.voted,false, "the voter has already voted");
+        /// returns an error while the ballot is paused
+        fn ensure_not_paused(&self) -> Result<()> {
+            if self.paused {
+                return Err(Error::Paused)
+            }
+            Ok(())
+        }
 
-            voter.weight = 1;
+        /// returns the number of blocks left until the deadline block,
+        /// `0` once it is reached, or `None` if there is no deadline
+        #[ink(message)]
+        pub fn blocks_until_deadline(&self) -> Option<BlockNumber> {
+            self.deadline
+                .map(|deadline| deadline.saturating_sub(self.env().block_number()))
         }
 
+        /// Sets the title and description telling what the ballot is for.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_metadata(&mut self, title: String, description: String) {
+            let caller = self.env().caller();
+            // only chair person can set the metadata
+            assert_eq!(caller,self.chair_person, "only chair person can set the metadata");
+            self.title = title;
+            self.description = description;
+        }
 
-        /// Give your vote (including votes delegated to you)
-        /// to proposal `proposals[proposal]`.
+        /// returns the title of the ballot, empty if it has none
         #[ink(message)]
-        pub fn vote(&mut self, proposal_index: i32) {
-            let sender_id = self.env().caller();
-            let sender_opt =  self.voters.get_mut(&sender_id);
+        pub fn title(&self) -> String {
+            self.title.clone()
+        }
 
-            //  ACTION: check if the person calling the function
-            //          is a voter
-            //        * check if the person has not already voted
-            //        * check if the person has right to vote
-            // 
+        /// returns the description of the ballot, empty if it has none
+        #[ink(message)]
+        pub fn description(&self) -> String {
+            self.description.clone()
+        }
 
-            assert_eq!(sender_opt.is_some(),true, "Sender is not a voter!");
+        /// Freezes the electorate at `block`: voters registered after it
+        /// cannot vote even if they are given the right to.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_snapshot_block(&mut self, block: BlockNumber) {
+            let caller = self.env().caller();
+            // only chair person can set the snapshot block
+            assert_eq!(caller,self.chair_person, "only chair person can set the snapshot block");
+            self.snapshot_block = Some(block);
+        }
 
-            let sender = sender_opt.unwrap();
-            assert_eq!(sender.voted,false, "You have already voted");
+        /// returns the block the electorate is frozen at, if any
+        #[ink(message)]
+        pub fn snapshot_block(&self) -> Option<BlockNumber> {
+            self.snapshot_block
+        }
 
-            assert_eq!(sender.weight,1, "You have no right to vote");
+        /// returns the start block of the ballot, if any
+        #[ink(message)]
+        pub fn get_start_block(&self) -> Option<BlockNumber> {
+            self.start_block
+        }
 
+        /// returns the maximum number of proposals, `0` if unlimited
+        #[ink(message)]
+        pub fn max_proposals(&self) -> u32 {
+            self.max_proposals
+        }
 
-            // get the proposal
-            let proposal_opt = self.proposals.get_mut(proposal_index as usize);
+        /// Limits the weight a voter can reach through delegations to
+        /// `cap`, `0` removes the limit. Delegations made before are kept.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_max_delegated_weight(&mut self, cap: u32) {
+            let caller = self.env().caller();
+            // only chair person can set the delegation cap
+            assert_eq!(caller,self.chair_person, "only chair person can set the delegation cap");
+            self.max_delegated_weight = cap;
+        }
 
-            //  ACTION: check if the proposal exists
-            //        * update voters.voted to true
-            //        * update voters.vote to index of proposal to which he voted
-            //        * Add weight of the voter to  proposals.vote_count 
+        /// returns the maximum weight reachable through delegations, `0` if unlimited
+        #[ink(message)]
+        pub fn max_delegated_weight(&self) -> u32 {
+            self.max_delegated_weight
+        }
 
-            assert_eq!(proposal_opt.is_some(),true, "Proposal index out of bound");
+        /// returns the number of proposals required before votes can be cast
+        #[ink(message)]
+        pub fn min_proposals(&self) -> u32 {
+            self.min_proposals
+        }
 
-            let proposal = proposal_opt.unwrap();
+        /// returns true once the current block has reached the start block
+        fn is_voting_started(&self) -> bool {
+            match self.start_block {
+                Some(start_block) => self.env().block_number() >= start_block,
+                None => true,
+            }
+        }
 
-            sender.voted = true;
-            sender.vote = Some(proposal_index);
+        /// returns true once the current block is past the deadline
+        fn is_voting_closed(&self) -> bool {
+            match self.deadline {
+                Some(deadline) => self.env().block_number() > deadline,
+                None => false,
+            }
+        }
 
-            proposal.vote_count += sender.weight;
+        /// returns an error once the ballot has been finalized
+        fn ensure_not_finalized(&self) -> Result<()> {
+            if self.finalized {
+                return Err(Error::AlreadyFinalized)
+            }
+            Ok(())
+        }
 
+        /// returns true while votes can be cast: the ballot is not paused,
+        /// the start block is reached and the deadline has not passed
+        #[ink(message)]
+        pub fn is_voting_open(&self) -> bool {
+            self.ensure_voting_open().is_ok()
         }
 
+        /// returns true if `who` could cast their vote right now: voting is
+        /// open and they are a voter with a usable voting right who has
+        /// neither voted nor delegated yet
+        #[ink(message)]
+        pub fn can_vote(&self, who: AccountId) -> bool {
+            self.ensure_can_vote(who).is_ok()
+        }
 
-            /// @dev Computes the winning proposal taking all
-            /// previous votes into account.
-            fn winning_proposal(&self) -> Option<usize> {
-                let mut winning_vote_vount:u32 = 0;
-                let mut winning_index: Option<usize> = None;
-                let mut index: usize = 0;
-
-                for val in self.proposals.iter() {
-                    if val.vote_count > winning_vote_vount {
-                        winning_vote_vount = val.vote_count;
-                        winning_index = Some(index);
-                    }
-                    index += 1
+        /// returns true if `who` is a registered voter, whether or not
+        /// they have been given the right to vote
+        #[ink(message)]
+        pub fn is_registered(&self, who: AccountId) -> bool {
+            self.voters.contains_key(&who)
+        }
 
-                }
-                return winning_index
+        /// returns the error a vote of `voter_id` would fail with,
+        /// not knowing the proposal voted for
+        fn ensure_can_vote(&self, voter_id: AccountId) -> Result<()> {
+            self.ensure_voting_open()?;
+            if !self.chair_can_vote && voter_id == self.chair_person {
+                return Err(Error::ChairCannotVote)
             }
+            let block = self.env().block_number();
+            match self.voters.get(&voter_id) {
+                Some(voter) if voter.voted => Err(Error::AlreadyVoted),
+                Some(voter) if !voter.has_right_at(block, self.snapshot_block) => Err(Error::NoVotingRight),
+                Some(_) => Ok(()),
+                None => Err(Error::NotAVoter),
+            }
+        }
 
+        /// returns the error `vote` would fail with if voting is not open
+        fn ensure_voting_open(&self) -> Result<()> {
+            self.ensure_not_paused()?;
+            self.ensure_not_finalized()?;
+            if !self.is_voting_started() {
+                return Err(Error::NotStarted)
+            }
+            if self.is_voting_closed() {
+                return Err(Error::VotingClosed)
+            }
+            if (self.proposals.len() as u32) < self.min_proposals {
+                return Err(Error::NotEnoughProposals)
+            }
+            Ok(())
+        }
 
-        // Calls winning_proposal() function to get the index
-        // of the winner contained in the proposals array and then
-        // returns the name of the winner
-        pub fn get_winning_proposal_name(&self) -> &String {
-            
-            //  ACTION: use winning_proposal to get the index of winning proposal
-            //        * check if any proposal has won
-            //        * return winnning proposal name if exists
-            let winner_index: Option<usize> = self.winning_proposal();
-            assert_eq!(winner_index.is_some(),true, "No Proposal!");
-            let index = winner_index.unwrap();
-            let proposal = self.proposals.get(index).unwrap();
-            return &proposal.name
+        pub fn get_voter(&self, voter_id: AccountId) -> Option<&Voter>{
+            self.voters.get(&voter_id)
+        }
 
+        pub fn get_voter_count(&self) -> usize{
+            self.voters.len() as usize
         }
 
+        /// returns the record of the given voter,
+        /// or `None` if the voter is not registered
+        #[ink(message)]
+        pub fn get_voter_info(&self, voter_id: AccountId) -> Option<VoterInfo> {
+            self.voters.get(&voter_id).map(VoterInfo::from)
+        }
 
-         /// Delegate your vote to the voter `to`.
-        /// If the `to` has already voted, you vote is casted to
-        /// the same candidate as `to`
+        /// returns the record of the caller,
+        /// or `None` if the caller is not registered
         #[ink(message)]
-        pub fn delegate(&mut self, to: AccountId)  {
+        pub fn my_voter_info(&self) -> Option<VoterInfo> {
+            self.get_voter_info(self.env().caller())
+        }
 
-            // account id of the person who invoked the function
-            let sender_id = self.env().caller();
-            let sender_weight;
-            // self delegation is not allowd
-            assert_ne!(to,sender_id, "Self-delegation is disallowed.");
-    
-            {
-                let sender_opt =  self.voters.get_mut(&sender_id);
-                // the voter invoking the function should exist in our ballot
-                assert_eq!(sender_opt.is_some(),true, "Caller is not a valid voter");
-                let sender = sender_opt.unwrap();
-    
-                // the voter must not have already casted their vote
-                assert_eq!(sender.voted,false, "You have already voted");
-    
-                sender.voted = true;
-                sender.delegate = Some(to);
-                sender_weight = sender.weight;
-            }
-    
-            {
-                let delegate_opt = self.voters.get_mut(&to);
-                // the person to whom the vote is being delegated must be a valid voter
-                assert_eq!(delegate_opt.is_some(),true, "The delegated address is not valid");
-    
-                let delegate = delegate_opt.unwrap();
-    
-                // the voter should not have already voted
-                if delegate.voted {
-                    // If the delegate already voted,
-                    // directly add to the number of votes
-                    let voted_to = delegate.vote.unwrap() as usize;
-                    self.proposals[voted_to].vote_count += sender_weight;
-                } else {
-                    // If the delegate did not vote yet,
-                    // add to her weight.
-                    delegate.weight += sender_weight;
-                }
+        /// returns the proposal index the caller voted for along with the
+        /// weight of their vote, or `None` if they have not voted directly
+        #[ink(message)]
+        pub fn my_receipt(&self) -> Option<(u32, u32)> {
+            let voter = self.voters.get(&self.env().caller())?;
+            if !voter.voted {
+                return None
             }
+            voter.vote.map(|vote| (vote, voter.weight))
         }
 
-    }
+        /// returns the record of the chair person, who is always a voter
+        #[ink(message)]
+        pub fn chairperson_voter(&self) -> VoterInfo {
+            let chair = self.voters.get(&self.chair_person).expect("the chair person is always a voter");
+            VoterInfo::from(chair)
+        }
 
-    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
-    /// module and test functions are marked with a `#[test]` attribute.
-    /// The below code is technically just normal Rust code.
-    #[cfg(test)]
-    mod tests {
-        /// Imports all the definitions from the outer scope so we can use them here.
-        use super::*;
-        
-        // Alias `ink_lang` so we can use `ink::test`.
-        use ink_lang as ink;
+        /// returns whether the given voter has voted,
+        /// or `None` if the voter is not registered
+        #[ink(message)]
+        pub fn has_voted(&self, voter_id: AccountId) -> Option<bool> {
+            self.voters.get(&voter_id).map(|voter| voter.voted)
+        }
+
+        /// returns the voting weight of the given voter,
+        /// or `None` if the voter is not registered
+        #[ink(message)]
+        pub fn voting_weight_of(&self, voter_id: AccountId) -> Option<u32> {
+            self.voters.get(&voter_id).map(|voter| voter.weight)
+        }
+
+        /// returns the number of registered voters
+        #[ink(message)]
+        pub fn voter_count(&self) -> u32 {
+            self.voters.len()
+        }
+
+        /// returns the number of voters who have been given the right to vote
+        #[ink(message)]
+        pub fn eligible_voter_count(&self) -> u32 {
+            self.voters.values().filter(|voter| voter.weight >= 1).count() as u32
+        }
+
+        /// returns the number of voters with the right to vote who have not voted
+        #[ink(message)]
+        pub fn abstention_count(&self) -> u32 {
+            self.voters
+                .values()
+                .filter(|voter| voter.weight >= 1 && !voter.voted)
+                .count() as u32
+        }
+
+        /// returns the account id of every registered voter,
+        /// including the chair person
+        #[ink(message)]
+        pub fn all_voters(&self) -> Vec<AccountId> {
+            self.voters.keys().cloned().collect()
+        }
+
+        /// returns a `(from, to)` pair for every voter who delegated their
+        /// vote, `to` being the voter who finally received it
+        #[ink(message)]
+        pub fn delegations(&self) -> Vec<(AccountId, AccountId)> {
+            self.voters
+                .iter()
+                .filter_map(|(voter_id, voter)| voter.delegate.map(|to| (*voter_id, to)))
+                .collect()
+        }
+
+        /// returns the voters with the right to vote who have not voted yet
+        #[ink(message)]
+        pub fn remaining_voters(&self) -> Vec<AccountId> {
+            self.voters
+                .iter()
+                .filter(|(_, voter)| voter.weight >= 1 && !voter.voted)
+                .map(|(voter_id, _)| *voter_id)
+                .collect()
+        }
+
+        /// returns the summed weight of the voters with the right to vote
+        /// who have not voted yet, the votes still able to change the result
+        #[ink(message)]
+        pub fn unvoted_eligible_weight(&self) -> u32 {
+            self.voters
+                .values()
+                .filter(|voter| voter.weight >= 1 && !voter.voted)
+                .fold(0, |sum, voter| sum.saturating_add(voter.weight))
+        }
+
+        /// returns the voters who voted for `proposals[index]` directly,
+        /// voters who delegated their vote are not listed
+        #[ink(message)]
+        pub fn voters_for_proposal(&self, index: u32) -> Vec<AccountId> {
+            self.voters
+                .iter()
+                .filter(|(_, voter)| voter.vote == Some(index))
+                .map(|(voter_id, _)| *voter_id)
+                .collect()
+        }
+
+        /// returns the turnout in basis points, the share of voters with
+        /// the right to vote who have voted, or `0` without such voters
+        #[ink(message)]
+        pub fn participation_bps(&self) -> u32 {
+            let eligible = self.eligible_voter_count();
+            if eligible == 0 {
+                return 0
+            }
+            let voted = eligible - self.abstention_count();
+            (voted as u64 * 10_000 / eligible as u64) as u32
+        }
+
+                /// the function adds the provided voter id into possible
+        /// list of voters. By default the voter has no voting right,
+        /// the contract owner must approve the voter before he can cast a vote
+        #[ink(message)]
+        pub fn add_voter(&mut self, voter_id: AccountId) -> Result<bool> {
+            self.ensure_not_paused()?;
+            // with registration closed only the chair person adds voters
+            if !self.registration_open && self.env().caller() != self.chair_person {
+                return Err(Error::RegistrationClosed)
+            }
+
+            let voter_opt = self.voters.get(&voter_id);
+            // the voter does not exists
+            if voter_opt.is_some() {
+                return Ok(false)
+            }
+
+            self.voters.insert(voter_id, Voter{
+                weight:0,
+                voted:false,
+                delegate: None,
+                vote: None,
+                commitment: None,
+                voted_at: None,
+                delegated_weight: 0,
+                rights_expiry: None,
+                registered_at: self.env().block_number(),
+            });
+            self.env().emit_event(VoterAdded { voter: voter_id });
+            Ok(true)
+        }
+
+        /// removes the provided voter id from the list of voters.
+        /// Only voters who have not been given the right to vote and
+        /// are not involved in any vote or delegation can be removed.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn remove_voter(&mut self, voter_id: AccountId) -> Result<()> {
+            self.ensure_not_paused()?;
+
+            let caller = self.env().caller();
+            // only chair person can remove voters
+            assert_eq!(caller,self.chair_person, "only chair person can remove voters");
+            // the chair person always stays a voter
+            assert_ne!(voter_id,self.chair_person, "the chair person cannot be removed");
+
+            let voter = match self.voters.get(&voter_id) {
+                Some(voter) => voter,
+                None => return Err(Error::NotAVoter),
+            };
+            if voter.weight != 0 || voter.voted {
+                return Err(Error::CannotRemoveActiveVoter)
+            }
+            if self.voters.values().any(|voter| voter.delegate == Some(voter_id)) {
+                return Err(Error::CannotRemoveActiveVoter)
+            }
+
+            self.voters.take(&voter_id);
+            Ok(())
+        }
+
+        /// adds all the provided voter ids into the list of voters,
+        /// skipping the ones that are already registered.
+        /// returns the number of voters actually added
+        #[ink(message)]
+        pub fn add_voters(&mut self, ids: Vec<AccountId>) -> Result<u32> {
+            let mut added: u32 = 0;
+            for voter_id in ids {
+                if self.add_voter(voter_id)? {
+                    added += 1;
+                }
+            }
+            Ok(added)
+        }
+
+
+
+        /// given an index returns the name of the proposal at that index
+        pub fn get_proposal_name_at_index(&self, index:usize) -> &String {
+            let proposal = self.proposals.get(index).unwrap();
+            return &proposal.name
+        }
+
+        /// given an index returns the description of the proposal at that index
+        #[ink(message)]
+        pub fn get_proposal_description(&self, index: u32) -> Option<String> {
+            self.proposals.get(index as usize).map(|proposal| proposal.description.clone())
+        }
+
+        /// given an index returns the URI of the proposal at that index,
+        /// or `None` if there is no such proposal or it has no URI
+        #[ink(message)]
+        pub fn get_proposal_uri(&self, index: u32) -> Option<String> {
+            self.proposals
+                .get(index as usize)
+                .filter(|proposal| !proposal.uri.is_empty())
+                .map(|proposal| proposal.uri.clone())
+        }
+
+        /// returns the number of proposals in ballet
+        pub fn get_proposal_count(&self) -> usize {
+            return self.proposals.len()
+        }
+
+        /// returns the number of votes cast across all proposals
+        #[ink(message)]
+        pub fn total_votes_cast(&self) -> u32 {
+            self.total_votes
+        }
+
+        /// returns true if enough votes have been cast for the result to be valid,
+        /// blank votes count towards the quorum
+        #[ink(message)]
+        pub fn has_quorum(&self) -> bool {
+            self.total_votes.saturating_add(self.blank_votes) >= self.quorum
+        }
+
+        /// adds the given proposal name in ballet
+        /// Should only be called by `chairperson` before proposals are locked.
+        /// Proposal names must be unique within the ballot.
+        #[ink(message)]
+        pub fn add_proposal(&mut self, proposal_name: String) -> Result<()> {
+            self.add_proposal_with_description(proposal_name, String::new())
+        }
+
+        /// adds the given proposal name along with its description in ballet
+        /// Should only be called by `chairperson` before proposals are locked.
+        /// Proposal names must be unique within the ballot and between 1
+        /// and `max_name_len` bytes long.
+        #[ink(message)]
+        pub fn add_proposal_with_description(&mut self, proposal_name: String, description: String) -> Result<()> {
+            self.insert_proposal(Proposal{
+                name: proposal_name,
+                vote_count: 0,
+                description,
+                threshold: 0,
+                uri: String::new(),
+                active: true,
+            })
+        }
+
+        /// adds the given proposal name in ballet along with the number of
+        /// votes it needs to be approved, see `approved_proposals`.
+        /// Should only be called by `chairperson` before proposals are locked.
+        #[ink(message)]
+        pub fn add_proposal_with_threshold(&mut self, proposal_name: String, threshold: u32) -> Result<()> {
+            self.insert_proposal(Proposal{
+                name: proposal_name,
+                vote_count: 0,
+                description: String::new(),
+                threshold,
+                uri: String::new(),
+                active: true,
+            })
+        }
+
+        /// adds the given proposal name in ballet along with a link, such as
+        /// an IPFS or HTTP URI, to its off-chain content. The URI is stored
+        /// verbatim but must not be empty.
+        /// Should only be called by `chairperson` before proposals are locked.
+        #[ink(message)]
+        pub fn add_proposal_with_uri(&mut self, proposal_name: String, uri: String) -> Result<()> {
+            if uri.is_empty() {
+                return Err(Error::ProposalUriInvalid)
+            }
+            self.insert_proposal(Proposal{
+                name: proposal_name,
+                vote_count: 0,
+                description: String::new(),
+                threshold: 0,
+                uri,
+                active: true,
+            })
+        }
+
+        /// adds the given proposal in ballet once the caller
+        /// and the proposal name have been validated
+        fn insert_proposal(&mut self, proposal: Proposal) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can add proposals
+            assert_eq!(caller,self.chair_person, "only chair person can add proposals");
+            self.push_proposal(proposal)
+        }
+
+        /// adds the given proposal in ballet once its name has been validated
+        fn push_proposal(&mut self, proposal: Proposal) -> Result<()> {
+            if self.proposals_locked {
+                return Err(Error::ProposalsLocked)
+            }
+
+            if self.max_proposals > 0 && self.proposals.len() as u32 >= self.max_proposals {
+                return Err(Error::TooManyProposals)
+            }
+
+            if proposal.name.is_empty() || proposal.name.len() > self.max_name_len as usize {
+                return Err(Error::ProposalNameInvalid)
+            }
+
+            if self.proposal_exists(proposal.name.clone()) {
+                return Err(Error::DuplicateProposal)
+            }
+
+            let name = proposal.name.clone();
+            self.proposals.push(proposal);
+            self.env().emit_event(ProposalAdded {
+                index: (self.proposals.len() - 1) as u32,
+                name,
+            });
+            Ok(())
+        }
+
+        /// Corrects the name of `proposals[index]`, e.g. to fix a typo.
+        /// Only proposals without votes can be renamed and the new name
+        /// follows the same rules as in `add_proposal`.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn rename_proposal(&mut self, index: u32, new_name: String) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can rename proposals
+            assert_eq!(caller,self.chair_person, "only chair person can rename proposals");
+
+            match self.proposals.get(index as usize) {
+                Some(proposal) if proposal.vote_count > 0 => return Err(Error::ProposalHasVotes),
+                Some(_) => {}
+                None => return Err(Error::ProposalIndexOutOfBounds),
+            }
+            if new_name.is_empty() || new_name.len() > self.max_name_len as usize {
+                return Err(Error::ProposalNameInvalid)
+            }
+            if self.proposal_exists(new_name.clone()) {
+                return Err(Error::DuplicateProposal)
+            }
+
+            let old_name = core::mem::replace(&mut self.proposals[index as usize].name, new_name.clone());
+            self.env().emit_event(ProposalRenamed {
+                index,
+                old_name,
+                new_name,
+            });
+            Ok(())
+        }
+
+        /// returns whether a proposal with exactly the given name exists,
+        /// so that duplicates can be detected before `add_proposal`
+        #[ink(message)]
+        pub fn proposal_exists(&self, name: String) -> bool {
+            self.proposals.iter().any(|existing| existing.name == name)
+        }
+
+        /// prevents any further proposal from being added to the ballot.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn lock_proposals(&mut self) {
+            let caller = self.env().caller();
+            // only chair person can lock proposals
+            assert_eq!(caller,self.chair_person, "only chair person can lock proposals");
+            self.proposals_locked = true;
+        }
+
+        /// removes the proposal at the given index from the ballot.
+        /// Should only be called by `chairperson`.
+        /// Only proposals without votes can be removed. As the following
+        /// proposals shift down by one, the stored vote of every voter who
+        /// voted for one of them is shifted as well so it keeps pointing
+        /// to the same proposal.
+        #[ink(message)]
+        pub fn remove_proposal(&mut self, index: u32) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can remove proposals
+            assert_eq!(caller,self.chair_person, "only chair person can remove proposals");
+            self.ensure_not_finalized()?;
+
+            let proposal = match self.proposals.get(index as usize) {
+                Some(proposal) => proposal,
+                None => return Err(Error::ProposalIndexOutOfBounds),
+            };
+            if proposal.vote_count > 0 {
+                return Err(Error::ProposalHasVotes)
+            }
+            self.ensure_no_pending_commitments()?;
+
+            self.take_proposal(index, None);
+            self.refresh_leader();
+            Ok(())
+        }
+
+        /// Enables or disables voting for `proposals[index]`. Votes the
+        /// proposal already received are kept.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_proposal_active(&mut self, index: u32, active: bool) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can enable or disable proposals
+            assert_eq!(caller,self.chair_person, "only chair person can enable or disable proposals");
+            self.ensure_not_finalized()?;
+
+            match self.proposals.get_mut(index as usize) {
+                Some(proposal) => {
+                    proposal.active = active;
+                    Ok(())
+                }
+                None => Err(Error::ProposalIndexOutOfBounds),
+            }
+        }
+
+        /// returns whether votes for `proposals[index]` are accepted
+        #[ink(message)]
+        pub fn is_proposal_active(&self, index: u32) -> Option<bool> {
+            self.proposals.get(index as usize).map(|proposal| proposal.active)
+        }
+
+        /// Merges the duplicate proposal `proposals[remove]` into
+        /// `proposals[keep]`. The votes of the removed proposal are added to
+        /// the kept one and its voters are moved over, the following
+        /// proposals shift down by one as with `remove_proposal`.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn merge_proposals(&mut self, keep: u32, remove: u32) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can merge proposals
+            assert_eq!(caller,self.chair_person, "only chair person can merge proposals");
+            self.ensure_not_finalized()?;
+
+            let proposal_count = self.proposals.len() as u32;
+            if keep >= proposal_count || remove >= proposal_count {
+                return Err(Error::ProposalIndexOutOfBounds)
+            }
+            if keep == remove {
+                return Err(Error::SameProposal)
+            }
+            self.ensure_no_pending_commitments()?;
+
+            let removed_votes = self.proposals[remove as usize].vote_count;
+            let kept = &mut self.proposals[keep as usize];
+            kept.vote_count = kept.vote_count.checked_add(removed_votes).ok_or(Error::Overflow)?;
+            self.take_proposal(remove, Some(keep));
+            self.refresh_leader();
+            Ok(())
+        }
+
+        /// fails if a voter committed a vote that is not revealed yet, as
+        /// the committed proposal index must keep its meaning
+        fn ensure_no_pending_commitments(&self) -> Result<()> {
+            if self.voters.values().any(|voter| voter.commitment.is_some() && !voter.voted) {
+                return Err(Error::CommitmentsPending)
+            }
+            Ok(())
+        }
+
+        /// removes `proposals[index]` and updates the stored votes and
+        /// rankings to the shifted indices. Votes for the removed proposal
+        /// are moved to `merged_into`, or dropped without it.
+        fn take_proposal(&mut self, index: u32, merged_into: Option<u32>) {
+            let shift = |vote: u32| -> Option<u32> {
+                let vote = if vote == index { merged_into? } else { vote };
+                Some(if vote > index { vote - 1 } else { vote })
+            };
+
+            self.proposals.remove(index as usize);
+            // the display order refers to the old indices
+            self.display_order.clear();
+            for voter in self.voters.values_mut() {
+                if let Some(vote) = voter.vote {
+                    voter.vote = shift(vote);
+                }
+            }
+            for ranking in self.rankings.values_mut() {
+                let mut shifted: Vec<u32> = Vec::new();
+                for vote in ranking.iter().filter_map(|vote| shift(*vote)) {
+                    if !shifted.contains(&vote) {
+                        shifted.push(vote);
+                    }
+                }
+                *ranking = shifted;
+            }
+        }
         
+        /// Adds `count` votes from a prior off-chain tally to
+        /// `proposals[index]`, for example when migrating a poll.
+        /// Only possible before the first vote is cast.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn seed_votes(&mut self, index: u32, count: u32) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can seed votes
+            assert_eq!(caller,self.chair_person, "only chair person can seed votes");
+            self.ensure_not_finalized()?;
+
+            if self.has_live_votes {
+                return Err(Error::SeedingClosed)
+            }
+            let proposal = match self.proposals.get_mut(index as usize) {
+                Some(proposal) => proposal,
+                None => return Err(Error::ProposalIndexOutOfBounds),
+            };
+            let vote_count = proposal.vote_count.checked_add(count).ok_or(Error::Overflow)?;
+            let total_votes = self.total_votes.checked_add(count).ok_or(Error::Overflow)?;
+
+            proposal.vote_count = vote_count;
+            self.total_votes = total_votes;
+            self.note_votes(index);
+            self.env().emit_event(VotesSeeded { index, count });
+            Ok(())
+        }
+
+        /// Give `voter` the right to vote on this ballot.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn give_voting_right(&mut self, voter_id: AccountId) -> Result<()> {
+            self.give_weighted_voting_right(voter_id, 1)
+        }
+
+        /// Give `voter` the right to vote on this ballot with
+        /// the provided voting `weight`.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn give_weighted_voting_right(&mut self, voter_id: AccountId, weight: u32) -> Result<()> {
+            self.ensure_not_paused()?;
+
+            let caller = self.env().caller();
+            let voter_opt = self.voters.get_mut(&voter_id);
+
+            // ACTION: check if the caller is the chair_person
+            //         * check if the voter_id exists in ballot
+            //         * check if voter has not already voted
+            //         * if everything alright update voters weight
+    
+
+            // only chair person can give right to vote
+            assert_eq!(caller,self.chair_person, "only chair person can give right to vote");
+
+            // the voter does not exists
+            assert_eq!(voter_opt.is_some(),true, "provided voterId does not exist");
+
+            let voter = voter_opt.unwrap();
+            // the voter should not have already voted
+            assert_eq!(voter.voted,false, "the voter has already voted");
+
+            voter.grant(weight)?;
+            self.env().emit_event(VotingRightGranted { voter: voter_id });
+            Ok(())
+        }
+
+        /// Give `voter` the right to vote on this ballot until the `expiry`
+        /// block, after which the right cannot be used anymore.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn give_voting_right_until(&mut self, voter_id: AccountId, expiry: BlockNumber) -> Result<()> {
+            self.give_voting_right(voter_id)?;
+            if let Some(voter) = self.voters.get_mut(&voter_id) {
+                voter.rights_expiry = Some(expiry);
+            }
+            Ok(())
+        }
+
+        /// Take the right to vote away from `voter` as long as they have
+        /// not voted or delegated yet and hold no delegated votes.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn revoke_voting_right(&mut self, voter_id: AccountId) -> Result<()> {
+            self.ensure_not_paused()?;
+
+            let caller = self.env().caller();
+            // only chair person can revoke the right to vote
+            assert_eq!(caller,self.chair_person, "only chair person can revoke the right to vote");
+
+            let voter = match self.voters.get_mut(&voter_id) {
+                Some(voter) => voter,
+                None => return Err(Error::NotAVoter),
+            };
+            if voter.voted {
+                return Err(Error::AlreadyVoted)
+            }
+            // the delegators would lose their votes
+            if voter.delegated_weight > 0 {
+                return Err(Error::HoldsDelegatedVotes)
+            }
+
+            voter.weight = 0;
+            self.env().emit_event(VotingRightRevoked { voter: voter_id });
+            Ok(())
+        }
+
+        /// Give all the provided `voters` the right to vote on this ballot.
+        /// Unknown voters, voters who already voted and voters whose
+        /// weight would overflow are skipped.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn give_voting_rights(&mut self, voters: Vec<AccountId>) -> Result<()> {
+            self.ensure_not_paused()?;
+
+            let caller = self.env().caller();
+
+            // only chair person can give right to vote
+            assert_eq!(caller,self.chair_person, "only chair person can give right to vote");
+
+            for voter_id in voters {
+                let granted = match self.voters.get_mut(&voter_id) {
+                    Some(voter) if !voter.voted => voter.grant(1).is_ok(),
+                    _ => false,
+                };
+                if granted {
+                    self.env().emit_event(VotingRightGranted { voter: voter_id });
+                }
+            }
+            Ok(())
+        }
+
+
+        /// Resets all votes so that the ballot can be reused for
+        /// another round. Voters keep their voting weight.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn reset_ballot(&mut self) {
+            let caller = self.env().caller();
+            // only chair person can reset the ballot
+            assert_eq!(caller,self.chair_person, "only chair person can reset the ballot");
+
+            for proposal in self.proposals.iter_mut() {
+                proposal.vote_count = 0;
+            }
+            for voter in self.voters.values_mut() {
+                voter.voted = false;
+                voter.vote = None;
+                voter.delegate = None;
+                voter.commitment = None;
+                voter.voted_at = None;
+                // delegators get their weight back with their vote
+                voter.weight = voter.weight.saturating_sub(voter.delegated_weight);
+                voter.delegated_weight = 0;
+            }
+            self.total_votes = 0;
+            self.blank_votes = 0;
+            self.leading_index = None;
+            self.leading_votes = 0;
+            self.has_live_votes = false;
+            self.finalized = false;
+            self.finalized_winner = None;
+            let ranked: Vec<AccountId> = self.rankings.keys().cloned().collect();
+            for voter_id in ranked {
+                self.rankings.take(&voter_id);
+            }
+            self.env().emit_event(BallotReset {});
+        }
+
+        /// Removes every voter but the chair person, who is registered
+        /// again like on deployment, and resets all votes as with
+        /// `reset_ballot`. Used to reuse the ballot for a new electorate.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn clear_voters(&mut self) {
+            // only chair person can clear the voters, checked by reset_ballot
+            self.reset_ballot();
+
+            let voter_ids: Vec<AccountId> = self.voters.keys().cloned().collect();
+            for voter_id in voter_ids {
+                self.voters.take(&voter_id);
+            }
+            self.voters.insert(self.chair_person, Voter{
+                weight: if self.chair_can_vote { 1 } else { 0 },
+                voted:false,
+                delegate: None,
+                vote: None,
+                commitment: None,
+                voted_at: None,
+                delegated_weight: 0,
+                rights_expiry: None,
+                registered_at: self.env().block_number(),
+            });
+        }
+
+        /// Give your vote (including votes delegated to you)
+        /// to proposal `proposals[proposal]`.
+        #[ink(message)]
+        pub fn vote(&mut self, proposal_index: u32) -> Result<()> {
+            self.ensure_voting_open()?;
+
+            let sender_id = self.env().caller();
+            self.cast_vote(sender_id, proposal_index)
+        }
+
+        /// Commit to a hidden vote while the ballot is open. The commitment
+        /// is the `Blake2x256` hash of the SCALE encoded
+        /// `(proposal_index, salt)` tuple, which is revealed with
+        /// `reveal_vote` once the deadline has passed.
+        #[ink(message)]
+        pub fn commit_vote(&mut self, commitment: [u8; 32]) -> Result<()> {
+            self.ensure_voting_open()?;
+
+            let sender_id = self.env().caller();
+            let block = self.env().block_number();
+            let sender = match self.voters.get_mut(&sender_id) {
+                Some(sender) => sender,
+                None => return Err(Error::NotAVoter),
+            };
+
+            if sender.voted {
+                return Err(Error::AlreadyVoted)
+            }
+
+            if !sender.has_right_at(block, self.snapshot_block) {
+                return Err(Error::NoVotingRight)
+            }
+
+            sender.commitment = Some(commitment);
+            Ok(())
+        }
+
+        /// Reveal the vote committed with `commit_vote` after the deadline
+        /// has passed. The vote is only counted if it matches the commitment.
+        #[ink(message)]
+        pub fn reveal_vote(&mut self, proposal_index: u32, salt: u64) -> Result<()> {
+            self.ensure_not_paused()?;
+            self.ensure_not_finalized()?;
+            if !self.is_voting_closed() {
+                return Err(Error::VotingNotClosed)
+            }
+
+            let sender_id = self.env().caller();
+            let commitment = match self.voters.get(&sender_id) {
+                Some(sender) => sender.commitment,
+                None => return Err(Error::NotAVoter),
+            };
+            let commitment = match commitment {
+                Some(commitment) => commitment,
+                None => return Err(Error::NoCommitment),
+            };
+
+            let revealed = self.env().hash_bytes::<ink_env::hash::Blake2x256>(
+                &scale::Encode::encode(&(proposal_index, salt)),
+            );
+            if revealed != commitment {
+                return Err(Error::CommitmentMismatch)
+            }
+
+            self.cast_vote(sender_id, proposal_index)
+        }
+
+        /// Formally abstain: your vote (including votes delegated to you)
+        /// is recorded as blank and counted by `blank_vote_count` without
+        /// going to any proposal.
+        #[ink(message)]
+        pub fn vote_blank(&mut self) -> Result<()> {
+            let sender_id = self.env().caller();
+            self.ensure_can_vote(sender_id)?;
+
+            let now = self.env().block_timestamp();
+            let sender = self.voters.get_mut(&sender_id).expect("checked above");
+            let weight = sender.weight;
+            self.blank_votes = self.blank_votes.checked_add(weight).ok_or(Error::Overflow)?;
+            sender.voted = true;
+            sender.vote = None;
+            sender.voted_at = Some(now);
+            self.has_live_votes = true;
+
+            self.env().emit_event(BlankVoteCast {
+                voter: sender_id,
+                weight,
+            });
+            self.finalize_on_quorum();
+            Ok(())
+        }
+
+        /// returns the summed weight of the blank votes
+        #[ink(message)]
+        pub fn blank_vote_count(&self) -> u32 {
+            self.blank_votes
+        }
+
+        /// Write in a new proposal and vote for it in one step. The proposal
+        /// follows the same rules as `add_proposal` but may be added by any
+        /// voter who is still able to vote and holds `min_weight_to_propose`.
+        /// Returns the index of the new proposal.
+        #[ink(message)]
+        pub fn propose_and_vote(&mut self, proposal_name: String) -> Result<u32> {
+            if self.voting_mode != VotingMode::Plurality {
+                return Err(Error::WrongVotingMode)
+            }
+            let sender_id = self.env().caller();
+            if sender_id != self.chair_person {
+                if let Some(sender) = self.voters.get(&sender_id) {
+                    if sender.weight < self.min_weight_to_propose {
+                        return Err(Error::InsufficientWeight)
+                    }
+                }
+            }
+            // the proposal is only added if the vote can be cast
+            self.ensure_can_vote(sender_id)?;
+
+            self.push_proposal(Proposal{
+                name: proposal_name,
+                vote_count: 0,
+                description: String::new(),
+                threshold: 0,
+                uri: String::new(),
+                active: true,
+            })?;
+            let proposal_index = (self.proposals.len() - 1) as u32;
+            self.cast_vote(sender_id, proposal_index)?;
+            Ok(proposal_index)
+        }
+
+        /// records the vote of `sender_id` for the given proposal
+        fn cast_vote(&mut self, sender_id: AccountId, proposal_index: u32) -> Result<()> {
+            if self.voting_mode != VotingMode::Plurality {
+                return Err(Error::WrongVotingMode)
+            }
+            if !self.chair_can_vote && sender_id == self.chair_person {
+                return Err(Error::ChairCannotVote)
+            }
+            let now = self.env().block_timestamp();
+            let block = self.env().block_number();
+
+            let sender = match self.voters.get_mut(&sender_id) {
+                Some(sender) => sender,
+                None => return Err(Error::NotAVoter),
+            };
+
+            //  ACTION: check if the person calling the function
+            //          is a voter
+            //        * check if the person has not already voted
+            //        * check if the person has right to vote
+            // 
+
+            if sender.voted {
+                return Err(Error::AlreadyVoted)
+            }
+
+            // delegates may hold more than a single vote, a voting
+            // right given until a block cannot be used after it
+            if !sender.has_right_at(block, self.snapshot_block) {
+                return Err(Error::NoVotingRight)
+            }
+
+            //  ACTION: check if the proposal exists
+            //        * update voters.voted to true
+            //        * update voters.vote to index of proposal to which he voted
+            //        * Add weight of the voter to  proposals.vote_count 
+
+            // get the proposal
+            let proposal = match self.proposals.get_mut(proposal_index as usize) {
+                Some(proposal) => proposal,
+                _ => return Err(Error::ProposalIndexOutOfBounds),
+            };
+            if !proposal.active {
+                return Err(Error::ProposalInactive)
+            }
+
+            let weight = sender.weight;
+            let vote_count = proposal.vote_count.checked_add(weight).ok_or(Error::Overflow)?;
+            let total_votes = self.total_votes.checked_add(weight).ok_or(Error::Overflow)?;
+
+            sender.voted = true;
+            sender.vote = Some(proposal_index);
+            sender.voted_at = Some(now);
+
+            proposal.vote_count = vote_count;
+            self.total_votes = total_votes;
+            self.has_live_votes = true;
+            self.note_votes(proposal_index);
+
+            self.env().emit_event(VoteCast {
+                voter: sender_id,
+                proposal_index,
+                weight,
+            });
+            self.finalize_on_quorum();
+            Ok(())
+        }
+
+        /// Rank the proposals in order of preference, starting with the
+        /// most preferred one. Only used by ballots in
+        /// `VotingMode::RankedChoice`, the first choice is counted as the
+        /// voter's vote and the full ranking by `compute_irv_winner`.
+        #[ink(message)]
+        pub fn vote_ranked(&mut self, ranking: Vec<u32>) -> Result<()> {
+            self.ensure_voting_open()?;
+            if self.voting_mode != VotingMode::RankedChoice {
+                return Err(Error::WrongVotingMode)
+            }
+
+            let sender_id = self.env().caller();
+            if !self.chair_can_vote && sender_id == self.chair_person {
+                return Err(Error::ChairCannotVote)
+            }
+            let now = self.env().block_timestamp();
+            let block = self.env().block_number();
+
+            let first_choice = match ranking.first() {
+                Some(first_choice) => *first_choice,
+                None => return Err(Error::InvalidRanking),
+            };
+            for (position, proposal_index) in ranking.iter().enumerate() {
+                match self.proposals.get(*proposal_index as usize) {
+                    Some(proposal) if !proposal.active => return Err(Error::ProposalInactive),
+                    Some(_) => {}
+                    None => return Err(Error::ProposalIndexOutOfBounds),
+                }
+                if ranking[..position].contains(proposal_index) {
+                    return Err(Error::InvalidRanking)
+                }
+            }
+
+            let sender = match self.voters.get_mut(&sender_id) {
+                Some(sender) => sender,
+                None => return Err(Error::NotAVoter),
+            };
+            if sender.voted {
+                return Err(Error::AlreadyVoted)
+            }
+            if !sender.has_right_at(block, self.snapshot_block) {
+                return Err(Error::NoVotingRight)
+            }
+
+            let weight = sender.weight;
+            let vote_count = self.proposals[first_choice as usize].vote_count.checked_add(weight).ok_or(Error::Overflow)?;
+            let total_votes = self.total_votes.checked_add(weight).ok_or(Error::Overflow)?;
+            sender.voted = true;
+            sender.vote = Some(first_choice);
+            sender.voted_at = Some(now);
+            self.proposals[first_choice as usize].vote_count = vote_count;
+            self.total_votes = total_votes;
+            self.rankings.insert(sender_id, ranking);
+            self.has_live_votes = true;
+            self.note_votes(first_choice);
+
+            self.env().emit_event(VoteCast {
+                voter: sender_id,
+                proposal_index: first_choice,
+                weight,
+            });
+            self.finalize_on_quorum();
+            Ok(())
+        }
+
+        /// Computes the winner of a ranked ballot with instant runoff.
+        /// Every round each ranking counts for its most preferred proposal
+        /// still in the race; a proposal with more than half of those votes
+        /// wins, otherwise the proposal with the fewest votes is eliminated,
+        /// the later one on a tie. Returns `None` if nobody ranked.
+        #[ink(message)]
+        pub fn compute_irv_winner(&self) -> Option<u32> {
+            let proposal_count = self.proposals.len();
+            let mut eliminated = ink_prelude::vec![false; proposal_count];
+
+            for _ in 0..proposal_count {
+                let mut tallies = ink_prelude::vec![0u64; proposal_count];
+                let mut total: u64 = 0;
+                for (voter_id, ranking) in self.rankings.iter() {
+                    let weight = self.voters.get(voter_id).map_or(0, |voter| voter.weight) as u64;
+                    let choice = ranking.iter().find(|index| !eliminated[**index as usize]);
+                    if let Some(choice) = choice {
+                        tallies[*choice as usize] += weight;
+                        total += weight;
+                    }
+                }
+                if total == 0 {
+                    return None
+                }
+
+                let remaining = (0..proposal_count).filter(|index| !eliminated[*index]);
+                // the earlier proposal leads on a tie
+                let leader = remaining.clone()
+                    .max_by_key(|index| (tallies[*index], core::cmp::Reverse(*index)))?;
+                if tallies[leader] * 2 > total || remaining.clone().count() == 1 {
+                    return Some(leader as u32)
+                }
+                let loser = remaining
+                    .min_by_key(|index| (tallies[*index], core::cmp::Reverse(*index)))?;
+                eliminated[loser] = true;
+            }
+            None
+        }
+
+        /// returns the proposal indices ranked by the given voter, if any
+        #[ink(message)]
+        pub fn get_ranking(&self, voter_id: AccountId) -> Option<Vec<u32>> {
+            self.rankings.get(&voter_id).cloned()
+        }
+
+        /// returns how ties between the leading proposals are broken
+        #[ink(message)]
+        pub fn tie_break(&self) -> TieBreak {
+            self.tie_break
+        }
+
+        /// returns how votes are cast and tallied on this ballot
+        #[ink(message)]
+        pub fn voting_mode(&self) -> VotingMode {
+            self.voting_mode
+        }
+
+        /// Take back your vote so that it can be cast again.
+        /// Only direct votes can be revoked, delegated votes cannot.
+        #[ink(message)]
+        pub fn revoke_vote(&mut self) -> Result<()> {
+            self.ensure_not_paused()?;
+            self.ensure_not_finalized()?;
+            // ranked votes are final
+            if self.voting_mode != VotingMode::Plurality {
+                return Err(Error::WrongVotingMode)
+            }
+            if self.is_voting_closed() {
+                return Err(Error::VotingClosed)
+            }
+
+            let sender_id = self.env().caller();
+            let sender = match self.voters.get_mut(&sender_id) {
+                Some(sender) => sender,
+                None => return Err(Error::NotAVoter),
+            };
+
+            if sender.delegate.is_some() {
+                return Err(Error::VoteWasDelegated)
+            }
+
+            let proposal_index = match sender.vote {
+                Some(proposal_index) if sender.voted => proposal_index,
+                _ => return Err(Error::HasNotVoted),
+            };
+
+            let proposal = match self.proposals.get_mut(proposal_index as usize) {
+                Some(proposal) => proposal,
+                None => return Err(Error::ProposalIndexOutOfBounds),
+            };
+
+            proposal.vote_count -= sender.weight;
+            self.total_votes -= sender.weight;
+            sender.voted = false;
+            sender.vote = None;
+            sender.voted_at = None;
+            self.refresh_leader();
+            Ok(())
+        }
+
+        /// Move your vote to proposal `proposals[new_proposal_index]`.
+        /// Only direct votes can be changed, delegated votes cannot.
+        #[ink(message)]
+        pub fn change_vote(&mut self, new_proposal_index: u32) -> Result<()> {
+            self.ensure_not_paused()?;
+            self.ensure_not_finalized()?;
+            // ranked votes are final
+            if self.voting_mode != VotingMode::Plurality {
+                return Err(Error::WrongVotingMode)
+            }
+            if self.is_voting_closed() {
+                return Err(Error::VotingClosed)
+            }
+
+            let sender_id = self.env().caller();
+            let now = self.env().block_timestamp();
+            let sender = match self.voters.get_mut(&sender_id) {
+                Some(sender) => sender,
+                None => return Err(Error::NotAVoter),
+            };
+
+            if sender.delegate.is_some() {
+                return Err(Error::VoteWasDelegated)
+            }
+
+            let old_proposal_index = match sender.vote {
+                Some(proposal_index) if sender.voted => proposal_index,
+                _ => return Err(Error::HasNotVoted),
+            };
+
+            match self.proposals.get(new_proposal_index as usize) {
+                Some(proposal) if !proposal.active => return Err(Error::ProposalInactive),
+                Some(_) => {}
+                None => return Err(Error::ProposalIndexOutOfBounds),
+            }
+            let old_proposal = match self.proposals.get_mut(old_proposal_index as usize) {
+                Some(proposal) => proposal,
+                None => return Err(Error::ProposalIndexOutOfBounds),
+            };
+
+            old_proposal.vote_count -= sender.weight;
+            self.proposals[new_proposal_index as usize].vote_count += sender.weight;
+            sender.vote = Some(new_proposal_index);
+            sender.voted_at = Some(now);
+            self.refresh_leader();
+            Ok(())
+        }
+
+
+            /// @dev Computes the winning proposal taking all
+            /// previous votes into account.
+            /// Ties are broken according to `tie_break`. Ranked ballots
+            /// are decided by instant runoff instead.
+            fn winning_proposal(&self) -> Option<usize> {
+                if self.voting_mode == VotingMode::RankedChoice {
+                    return self.compute_irv_winner().map(|index| index as usize)
+                }
+                if self.tie_break == TieBreak::LowestIndex {
+                    return self.leading_index.map(|index| index as usize)
+                }
+                let winning_vote_count = self.proposals
+                    .iter()
+                    .map(|proposal| proposal.vote_count)
+                    .max()
+                    .filter(|vote_count| *vote_count > 0)?;
+                let mut leaders = self.proposals
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, proposal)| proposal.vote_count == winning_vote_count)
+                    .map(|(index, _)| index);
+
+                match self.tie_break {
+                    TieBreak::LowestIndex => leaders.next(),
+                    TieBreak::HighestIndex => leaders.next_back(),
+                    TieBreak::None => {
+                        let winning_index = leaders.next();
+                        if leaders.next().is_some() {
+                            return None
+                        }
+                        winning_index
+                    }
+                }
+            }
+
+
+        /// Declares the outcome of the ballot once the deadline has passed
+        /// or the proposals are locked. The winner is computed once and
+        /// no further votes are accepted.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn finalize(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can finalize the ballot
+            assert_eq!(caller,self.chair_person, "only chair person can finalize the ballot");
+
+            self.ensure_not_finalized()?;
+            if !self.is_voting_closed() && !self.proposals_locked {
+                return Err(Error::VotingNotClosed)
+            }
+
+            self.declare_winner();
+            Ok(())
+        }
+
+        /// Sets the weight a voter needs to write in a proposal with
+        /// `propose_and_vote`, `0` lets every voter do so.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_min_weight_to_propose(&mut self, weight: u32) {
+            let caller = self.env().caller();
+            // only chair person can set the weight needed to propose
+            assert_eq!(caller,self.chair_person, "only chair person can set the weight needed to propose");
+            self.min_weight_to_propose = weight;
+        }
+
+        /// returns the weight a voter needs to write in a proposal
+        #[ink(message)]
+        pub fn min_weight_to_propose(&self) -> u32 {
+            self.min_weight_to_propose
+        }
+
+        /// Limits how often a delegation is forwarded along a chain of
+        /// delegates to `depth`, `0` removes the limit.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_max_delegation_depth(&mut self, depth: u32) {
+            let caller = self.env().caller();
+            // only chair person can set the delegation depth
+            assert_eq!(caller,self.chair_person, "only chair person can set the delegation depth");
+            self.max_delegation_depth = depth;
+        }
+
+        /// returns how often a delegation may be forwarded, `0` if unlimited
+        #[ink(message)]
+        pub fn max_delegation_depth(&self) -> u32 {
+            self.max_delegation_depth
+        }
+
+        /// Makes `vote` finalize the ballot once the quorum is met, with a
+        /// quorum of `0` the first vote does.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_auto_finalize(&mut self, enabled: bool) {
+            let caller = self.env().caller();
+            // only chair person can change auto finalization
+            assert_eq!(caller,self.chair_person, "only chair person can change auto finalization");
+            self.auto_finalize = enabled;
+        }
+
+        /// returns whether the ballot is finalized once the quorum is met
+        #[ink(message)]
+        pub fn auto_finalize(&self) -> bool {
+            self.auto_finalize
+        }
+
+        /// finalizes the ballot if `auto_finalize` is set and the votes
+        /// counted so far meet the quorum, called after every new vote
+        fn finalize_on_quorum(&mut self) {
+            if self.auto_finalize && !self.finalized && self.has_quorum() {
+                self.declare_winner();
+            }
+        }
+
+        /// computes the winner, stops further votes and emits
+        /// `WinnerDeclared` if there is a winner
+        fn declare_winner(&mut self) {
+            self.finalized = true;
+            self.finalized_winner = self.winning_proposal().map(|index| index as u32);
+            if let Some(index) = self.finalized_winner {
+                let proposal = &self.proposals[index as usize];
+                self.env().emit_event(WinnerDeclared {
+                    index,
+                    name: proposal.name.clone(),
+                    votes: proposal.vote_count,
+                });
+            }
+        }
+
+        /// returns the index of the winner declared by `finalize`,
+        /// or `None` if the ballot is not finalized or had no votes
+        #[ink(message)]
+        pub fn finalized_winner(&self) -> Option<u32> {
+            self.finalized_winner
+        }
+
+        /// returns the index and vote count of the proposal currently
+        /// leading, the earlier proposal on a tie. The value is cached
+        /// and does not require a scan of the proposals.
+        #[ink(message)]
+        pub fn leading_proposal(&self) -> Option<(u32, u32)> {
+            self.leading_index.map(|index| (index, self.leading_votes))
+        }
+
+        /// updates the cached leader after the votes of
+        /// `proposals[index]` went up
+        fn note_votes(&mut self, index: u32) {
+            let votes = self.proposals[index as usize].vote_count;
+            let overtakes = match self.leading_index {
+                Some(leader) => votes > self.leading_votes || (votes == self.leading_votes && index < leader),
+                None => votes > 0,
+            };
+            if overtakes {
+                self.leading_index = Some(index);
+                self.leading_votes = votes;
+            }
+        }
+
+        /// recomputes the cached leader from scratch, needed whenever
+        /// votes are taken away or proposals are moved
+        fn refresh_leader(&mut self) {
+            self.leading_index = None;
+            self.leading_votes = 0;
+            for index in 0..self.proposals.len() {
+                self.note_votes(index as u32);
+            }
+        }
+
+        /// returns the indices of the proposals which reached their
+        /// approval threshold. Proposals without threshold are never approved.
+        #[ink(message)]
+        pub fn approved_proposals(&self) -> Vec<u32> {
+            self.proposals
+                .iter()
+                .enumerate()
+                .filter(|(_, proposal)| proposal.threshold > 0 && proposal.vote_count >= proposal.threshold)
+                .map(|(index, _)| index as u32)
+                .collect()
+        }
+
+        /// returns the indices of the proposals which have not received
+        /// any vote, candidates for `remove_proposal`
+        #[ink(message)]
+        pub fn proposals_with_zero_votes(&self) -> Vec<u32> {
+            self.proposals
+                .iter()
+                .enumerate()
+                .filter(|(_, proposal)| proposal.vote_count == 0)
+                .map(|(index, _)| index as u32)
+                .collect()
+        }
+
+        /// returns the index, name and vote count of every proposal
+        #[ink(message)]
+        pub fn all_proposals(&self) -> Vec<(u32, String, u32)> {
+            self.proposals
+                .iter()
+                .enumerate()
+                .map(|(index, proposal)| (index as u32, proposal.name.clone(), proposal.vote_count))
+                .collect()
+        }
+
+        /// returns the name and vote count of every proposal, sorted by
+        /// descending vote count. Ties keep the order of the proposals.
+        #[ink(message)]
+        pub fn get_results(&self) -> Vec<(String, u32)> {
+            let mut results: Vec<(String, u32)> = self.proposals
+                .iter()
+                .map(|proposal| (proposal.name.clone(), proposal.vote_count))
+                .collect();
+            // the sort is stable so ties stay ordered by index
+            results.sort_by_key(|result| core::cmp::Reverse(result.1));
+            results
+        }
+
+        /// returns the name and vote count of every proposal in index
+        /// order, for off-chain backups. The size grows with the number
+        /// of proposals, which `max_proposals` can bound.
+        #[ink(message)]
+        pub fn export_results(&self) -> Vec<(String, u32)> {
+            self.proposals
+                .iter()
+                .map(|proposal| (proposal.name.clone(), proposal.vote_count))
+                .collect()
+        }
+
+        /// returns the id, weight and voted flag of every registered voter,
+        /// for off-chain backups. The size grows with the number of voters.
+        #[ink(message)]
+        pub fn export_voters(&self) -> Vec<(AccountId, u32, bool)> {
+            self.voters
+                .iter()
+                .map(|(voter_id, voter)| (*voter_id, voter.weight, voter.voted))
+                .collect()
+        }
+
+        /// Sets the order in which `ordered_proposals` lists the proposals,
+        /// without changing their indices. `order` must contain every
+        /// proposal index exactly once.
+        /// Should only be called by `chairperson`.
+        #[ink(message)]
+        pub fn set_display_order(&mut self, order: Vec<u32>) -> Result<()> {
+            let caller = self.env().caller();
+            // only chair person can set the display order
+            assert_eq!(caller,self.chair_person, "only chair person can set the display order");
+
+            let mut sorted = order.clone();
+            sorted.sort_unstable();
+            let is_permutation = sorted.len() == self.proposals.len()
+                && sorted.iter().enumerate().all(|(position, index)| *index == position as u32);
+            if !is_permutation {
+                return Err(Error::InvalidDisplayOrder)
+            }
+            self.display_order = order;
+            Ok(())
+        }
+
+        /// returns the name and vote count of every proposal in display
+        /// order. Index order is used if no display order is set or if
+        /// proposals were added or removed since.
+        #[ink(message)]
+        pub fn ordered_proposals(&self) -> Vec<(String, u32)> {
+            if self.display_order.len() != self.proposals.len() {
+                return self.export_results()
+            }
+            self.display_order
+                .iter()
+                .map(|index| &self.proposals[*index as usize])
+                .map(|proposal| (proposal.name.clone(), proposal.vote_count))
+                .collect()
+        }
+
+        /// returns the name and vote count of the proposal in second place.
+        /// Returns `None` with less than two proposals or if the second
+        /// place is shared with another proposal.
+        #[ink(message)]
+        pub fn runner_up(&self) -> Option<(String, u32)> {
+            let mut results = self.get_results().into_iter();
+            let (_, first_votes) = results.next()?;
+            let (name, votes) = results.next()?;
+            let third_votes = results.next().map(|(_, votes)| votes);
+            if votes == first_votes || third_votes == Some(votes) {
+                return None
+            }
+            Some((name, votes))
+        }
+
+        /// returns the 1-based place of `proposals[index]` by vote count,
+        /// tied proposals share the better place
+        #[ink(message)]
+        pub fn proposal_rank(&self, index: u32) -> Option<u32> {
+            let vote_count = self.proposals.get(index as usize)?.vote_count;
+            self.get_results()
+                .iter()
+                .position(|(_, votes)| *votes == vote_count)
+                .map(|position| position as u32 + 1)
+        }
+
+        /// returns by how many votes the leading proposal is ahead of the
+        /// second one, `0` on a tie. Returns `None` with less than two
+        /// proposals.
+        #[ink(message)]
+        pub fn victory_margin(&self) -> Option<u32> {
+            let mut results = self.get_results().into_iter();
+            let (_, first_votes) = results.next()?;
+            let (_, second_votes) = results.next()?;
+            Some(first_votes - second_votes)
+        }
+
+        /// Calls winning_proposal() function to get the index
+        /// of the winner contained in the proposals array and then
+        /// returns the name of the winner, along with a flag telling
+        /// whether other proposals share the same vote count.
+        /// Returns `None` if no proposal has received a vote yet
+        /// or if the quorum has not been reached.
+        #[ink(message)]
+        pub fn get_winning_proposal_name(&self) -> Option<(String, bool)> {
+            
+            //  ACTION: use winning_proposal to get the index of winning proposal
+            //        * check if any proposal has won
+            //        * return winnning proposal name if exists
+            if !self.has_quorum() {
+                return None
+            }
+            let index = self.winning_proposal()?;
+            let winner = self.proposals.get(index)?;
+            let is_tie = self.proposals
+                .iter()
+                .filter(|proposal| proposal.vote_count == winner.vote_count)
+                .count() > 1;
+            Some((winner.name.clone(), is_tie))
+        }
+
+
+        /// returns the winner, its vote count and whether the result is
+        /// valid in one call. Unlike `get_winning_proposal_name` the winner
+        /// is returned even if the quorum has not been reached.
+        #[ink(message)]
+        pub fn final_result(&self) -> FinalResult {
+            let winner_index = self.winning_proposal();
+            let top_votes = self.proposals
+                .iter()
+                .map(|proposal| proposal.vote_count)
+                .max()
+                .unwrap_or(0);
+            let is_tie = top_votes > 0 && self.proposals
+                .iter()
+                .filter(|proposal| proposal.vote_count == top_votes)
+                .count() > 1;
+            // the instant runoff winner may not have the most first choices
+            let winner_votes = winner_index.map_or(top_votes, |index| self.proposals[index].vote_count);
+            FinalResult {
+                winner_index: winner_index.map(|index| index as u32),
+                winner_name: winner_index.map(|index| self.proposals[index].name.clone()),
+                winner_votes,
+                quorum_met: self.has_quorum(),
+                is_tie,
+            }
+        }
+
+         /// Delegate your vote to the voter `to`.
+        /// If the `to` has already voted, you vote is casted to
+        /// the same candidate as `to`
+        #[ink(message)]
+        pub fn delegate(&mut self, to: AccountId) -> Result<()> {
+            self.ensure_voting_open()?;
+
+            // account id of the person who invoked the function
+            let sender_id = self.env().caller();
+            let sender_weight;
+            // self delegation is not allowd
+            if to == sender_id {
+                return Err(Error::SelfDelegation)
+            }
+
+            if !self.chair_can_vote && sender_id == self.chair_person {
+                return Err(Error::ChairCannotVote)
+            }
+
+            let block = self.env().block_number();
+            match self.voters.get(&sender_id) {
+                // the voter must not have already casted their vote
+                Some(sender) if sender.voted => return Err(Error::AlreadyVoted),
+                // a vote that cannot be cast cannot be delegated either
+                Some(sender) if !sender.has_right_at(block, self.snapshot_block) => return Err(Error::NoVotingRight),
+                Some(_) => {}
+                // the voter invoking the function should exist in our ballot
+                None => return Err(Error::NotAVoter),
+            }
+            // the person to whom the vote is being delegated must be a valid voter
+            if self.voters.get(&to).is_none() {
+                return Err(Error::DelegateNotFound)
+            }
+
+            let to = self.resolve_delegate(sender_id, to, self.max_delegation_depth)?;
+
+            {
+                let sender = self.voters.get_mut(&sender_id).expect("checked above");
+                sender_weight = sender.weight;
+            }
+
+            self.add_delegated_weight(to, sender_weight)?;
+            if let Some(sender) = self.voters.get_mut(&sender_id) {
+                sender.voted = true;
+                sender.delegate = Some(to);
+            }
+
+            self.env().emit_event(Delegated {
+                from: sender_id,
+                to,
+                weight: sender_weight,
+            });
+            // the delegate may have voted already
+            self.finalize_on_quorum();
+            Ok(())
+        }
+
+        /// Move your delegated vote from your current delegate to the
+        /// voter `to`. Only votes that were delegated can be redelegated,
+        /// and only as long as the delegate has neither voted nor delegated.
+        #[ink(message)]
+        pub fn redelegate(&mut self, to: AccountId) -> Result<()> {
+            self.ensure_not_paused()?;
+            self.ensure_not_finalized()?;
+            if self.is_voting_closed() {
+                return Err(Error::VotingClosed)
+            }
+
+            let sender_id = self.env().caller();
+            // self delegation is not allowd
+            if to == sender_id {
+                return Err(Error::SelfDelegation)
+            }
+            if self.voters.get(&to).is_none() {
+                return Err(Error::DelegateNotFound)
+            }
+
+            let (previous, sender_weight) = match self.voters.get(&sender_id) {
+                Some(sender) => match sender.delegate {
+                    Some(previous) => (previous, sender.weight),
+                    // a direct vote cannot be turned into a delegation
+                    None if sender.voted => return Err(Error::AlreadyVoted),
+                    None => return Err(Error::NotDelegated),
+                },
+                None => return Err(Error::NotAVoter),
+            };
+
+            // a delegate who voted or delegated further already used the
+            // weight as part of their own
+            if matches!(self.voters.get(&previous), Some(delegate) if delegate.voted) {
+                return Err(Error::DelegateAlreadyVoted)
+            }
+            let to = self.resolve_delegate(sender_id, to, self.max_delegation_depth)?;
+
+            // adding first leaves the previous delegate untouched on overflow
+            self.add_delegated_weight(to, sender_weight)?;
+            self.remove_delegated_weight(previous, sender_weight);
+            if let Some(sender) = self.voters.get_mut(&sender_id) {
+                sender.delegate = Some(to);
+            }
+
+            self.env().emit_event(Delegated {
+                from: sender_id,
+                to,
+                weight: sender_weight,
+            });
+            // the delegate may have voted already
+            self.finalize_on_quorum();
+            Ok(())
+        }
+
+        /// Take back a delegated vote as long as the delegate has neither
+        /// voted nor delegated yet, so that it can be cast or delegated again.
+        #[ink(message)]
+        pub fn clear_delegation(&mut self) -> Result<()> {
+            self.ensure_not_paused()?;
+            self.ensure_not_finalized()?;
+            if self.is_voting_closed() {
+                return Err(Error::VotingClosed)
+            }
+
+            let sender_id = self.env().caller();
+            let (previous, sender_weight) = match self.voters.get(&sender_id) {
+                Some(sender) => match sender.delegate {
+                    Some(previous) => (previous, sender.weight),
+                    None => return Err(Error::NotDelegated),
+                },
+                None => return Err(Error::NotAVoter),
+            };
+
+            // a delegate who delegated further passed the weight on as
+            // part of their own, it cannot be taken back from them anymore
+            if matches!(self.voters.get(&previous), Some(delegate) if delegate.voted) {
+                return Err(Error::DelegateAlreadyVoted)
+            }
+
+            self.remove_delegated_weight(previous, sender_weight);
+            if let Some(sender) = self.voters.get_mut(&sender_id) {
+                sender.voted = false;
+                sender.delegate = None;
+            }
+            Ok(())
+        }
+
+        /// forward the delegation through the chain starting at `to`
+        /// until a voter who did not delegate is found. The chain must
+        /// not lead back to `sender_id` and the walk is bounded by the
+        /// number of voters, and by `max_depth` forwards unless it is 0
+        fn resolve_delegate(&self, sender_id: AccountId, to: AccountId, max_depth: u32) -> Result<AccountId> {
+            let mut to = to;
+            for depth in 0..self.voters.len() {
+                match self.voters.get(&to).and_then(|voter| voter.delegate) {
+                    Some(next) if next == sender_id => return Err(Error::DelegationLoop),
+                    Some(_) if max_depth > 0 && depth >= max_depth => return Err(Error::DelegationTooDeep),
+                    Some(next) => to = next,
+                    None => break,
+                }
+            }
+            if self.voters.get(&to).and_then(|voter| voter.delegate).is_some() {
+                return Err(Error::DelegationLoop)
+            }
+            Ok(to)
+        }
+
+        /// hands `weight` over to the delegate `to`, nothing is changed
+        /// if a vote count would overflow or the delegate would exceed
+        /// `max_delegated_weight`
+        fn add_delegated_weight(&mut self, to: AccountId, weight: u32) -> Result<()> {
+            let cap = self.max_delegated_weight;
+            // the person to whom the vote is being delegated must be a valid voter
+            let delegate = match self.voters.get_mut(&to) {
+                Some(delegate) => delegate,
+                None => return Err(Error::DelegateNotFound),
+            };
+            let delegate_weight = delegate.weight.checked_add(weight).ok_or(Error::Overflow)?;
+            // own weight plus everything delegated so far, also after voting
+            if cap > 0 && delegate_weight > cap {
+                return Err(Error::DelegationCapExceeded)
+            }
+            let delegated_weight = delegate.delegated_weight.checked_add(weight).ok_or(Error::Overflow)?;
+
+            let mut raised = None;
+            // a blank vote of the delegate counts the delegated weight as blank
+            if delegate.voted && delegate.vote.is_none() {
+                self.blank_votes = self.blank_votes.checked_add(weight).ok_or(Error::Overflow)?;
+            // the voter should not have already voted
+            } else if delegate.voted {
+                // If the delegate already voted,
+                // directly add to the number of votes
+                let voted_to = match delegate.vote {
+                    Some(vote) if (vote as usize) < self.proposals.len() => vote as usize,
+                    _ => return Err(Error::StaleVoteIndex),
+                };
+                let vote_count = self.proposals[voted_to].vote_count.checked_add(weight).ok_or(Error::Overflow)?;
+                let total_votes = self.total_votes.checked_add(weight).ok_or(Error::Overflow)?;
+                self.proposals[voted_to].vote_count = vote_count;
+                self.total_votes = total_votes;
+                raised = Some(voted_to as u32);
+            }
+            // the weight grows whether or not the delegate voted, so that
+            // revoking or changing the vote moves the delegated votes too
+            delegate.weight = delegate_weight;
+            delegate.delegated_weight = delegated_weight;
+            if let Some(index) = raised {
+                self.note_votes(index);
+            }
+            Ok(())
+        }
+
+        /// takes `weight` back from the delegate `to`, undoing
+        /// `add_delegated_weight` for the vote the delegate holds now
+        fn remove_delegated_weight(&mut self, to: AccountId, weight: u32) {
+            let delegate = self.voters.get_mut(&to).expect("delegates are never removed");
+            delegate.delegated_weight = delegate.delegated_weight.saturating_sub(weight);
+            delegate.weight = delegate.weight.saturating_sub(weight);
+            if delegate.voted && delegate.vote.is_none() {
+                self.blank_votes = self.blank_votes.saturating_sub(weight);
+            } else if delegate.voted {
+                if let Some(proposal) = delegate.vote.and_then(|vote| self.proposals.get_mut(vote as usize)) {
+                    proposal.vote_count = proposal.vote_count.saturating_sub(weight);
+                }
+                self.total_votes = self.total_votes.saturating_sub(weight);
+                self.refresh_leader();
+            }
+        }
+
+    }
+
+    impl BallotReader for Ballot {
+        #[ink(message)]
+        fn winning_proposal_index(&self) -> Option<u32> {
+            self.winning_proposal().map(|index| index as u32)
+        }
+
+        #[ink(message)]
+        fn get_proposal_votes(&self, index: u32) -> Option<u32> {
+            self.proposals.get(index as usize).map(|proposal| proposal.vote_count)
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+        
+        // Alias `ink_lang` so we can use `ink::test`.
+        use ink_lang as ink;
+
+        type Event = <Ballot as ::ink_lang::BaseEvent>::Type;
+
+        fn default_accounts() -> ink_env::test::DefaultAccounts<ink_env::DefaultEnvironment> {
+            ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
+                .expect("off-chain environment should have been initialized already")
+        }
+
+        /// Returns the topic an account id is recorded as in emitted events.
+        fn account_topic(account: AccountId) -> Hash {
+            let mut topic = [0x0; 32];
+            topic.copy_from_slice(&scale::Encode::encode(&account));
+            Hash::from(topic)
+        }
+
+        /// Makes `caller` the account invoking the next contract calls.
+        fn set_caller(caller: AccountId) {
+            let callee = ink_env::account_id::<ink_env::DefaultEnvironment>()
+                .unwrap_or_else(|_| [0x0; 32].into());
+            ink_env::test::push_execution_context::<ink_env::DefaultEnvironment>(
+                caller,
+                callee,
+                1000000,
+                1000000,
+                ink_env::test::CallData::new(ink_env::call::Selector::new([0x00; 4])),
+            );
+        }
+        
+        #[ink::test]
+        fn new_works() {
+            let mut proposal_names: Vec<String> = Vec::new();
+            proposal_names.push(String::from("Proposal # 1"));  
+            let ballot = Ballot::new(Some(proposal_names), None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.get_proposal_count(),1);
+        }
+
+        #[ink::test]
+        fn default_works() {
+            let ballot = Ballot::default();
+            assert_eq!(ballot.get_proposal_count(), 0);
+        }
+
+        #[ink::test]
+        fn adding_proposals_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert_eq!(ballot.get_proposal_count(),1);
+        }
+
+        #[ink::test]
+        fn proposal_description_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal_with_description(
+                String::from("Proposal #2"),
+                String::from("Build a new bridge"),
+            ).unwrap();
+
+            assert_eq!(ballot.get_proposal_description(0), Some(String::new()));
+            assert_eq!(ballot.get_proposal_description(1), Some(String::from("Build a new bridge")));
+            assert_eq!(ballot.get_proposal_description(2), None);
+        }
+
+        #[ink::test]
+        fn proposal_name_length_is_validated() {
+            let mut ballot = Ballot::new(None, None, None, 0, 8, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.add_proposal(String::new()), Err(Error::ProposalNameInvalid));
+            assert_eq!(ballot.add_proposal(String::from("Too long!")), Err(Error::ProposalNameInvalid));
+            assert_eq!(ballot.add_proposal(String::from("Just ok!")), Ok(()));
+            assert_eq!(ballot.get_proposal_count(), 1);
+        }
+
+        #[ink::test]
+        fn adding_duplicate_proposal_fails() {
+            let mut ballot = Ballot::default();
+            assert_eq!(ballot.add_proposal(String::from("A")), Ok(()));
+            assert_eq!(ballot.add_proposal(String::from("A")), Err(Error::DuplicateProposal));
+            assert_eq!(ballot.get_proposal_count(), 1);
+        }
+
+        #[ink::test]
+        fn locking_proposals_works() {
+            let mut ballot = Ballot::default();
+            assert_eq!(ballot.add_proposal(String::from("Proposal #1")), Ok(()));
+            ballot.lock_proposals();
+            assert_eq!(ballot.add_proposal(String::from("Proposal #2")), Err(Error::ProposalsLocked));
+            assert_eq!(ballot.get_proposal_count(), 1);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "only chair person can add proposals")]
+        fn adding_proposals_fails_for_non_chairperson() {
+            let mut ballot = Ballot::default();
+            set_caller(default_accounts().bob);
+            let _ = ballot.add_proposal(String::from("Proposal #1"));
+        }
+
+        #[ink::test]
+        fn removing_proposal_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(1).unwrap();
+
+            assert_eq!(ballot.remove_proposal(0), Ok(()));
+            assert_eq!(ballot.get_proposal_count(), 1);
+            assert_eq!(ballot.get_proposal_name_at_index(0), "Proposal #2");
+
+            // the stored vote still points to the voted proposal
+            let voter = ballot.get_voter(ballot.get_chairperson()).unwrap();
+            assert_eq!(voter.vote, Some(0));
+            assert_eq!(ballot.revoke_vote(), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+        }
+
+        #[ink::test]
+        fn removing_proposal_with_votes_fails() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.vote(0).unwrap();
+
+            assert_eq!(ballot.remove_proposal(0), Err(Error::ProposalHasVotes));
+            assert_eq!(ballot.remove_proposal(1), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.get_proposal_count(), 1);
+        }
+
+        #[ink::test]
+        fn adding_voters_work() {
+            let mut ballot = Ballot::default();
+            let account_id = AccountId::from([0x0; 32]);
+            assert_eq!(ballot.add_voter(account_id),Ok(true));
+            assert_eq!(ballot.add_voter(account_id),Ok(false));
+        }
+
+        #[ink::test]
+        fn adding_voter_emits_voter_added_event() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            assert_eq!(ballot.add_voter(bob), Ok(true));
+            assert_eq!(ballot.add_voter(bob), Ok(false));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(emitted_events.len(), 1);
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events[0].data[..])
+                .expect("encountered invalid contract event data buffer");
+            if let Event::VoterAdded(VoterAdded { voter }) = decoded {
+                assert_eq!(voter, bob);
+            } else {
+                panic!("encountered unexpected event kind: expected a VoterAdded event")
+            }
+        }
+
+        #[ink::test]
+        fn adding_multiple_voters_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_voter(accounts.bob).unwrap();
+
+            let added = ballot.add_voters(vec![
+                accounts.bob,
+                accounts.charlie,
+                accounts.django,
+                accounts.charlie,
+            ]);
+            assert_eq!(added, Ok(2));
+            assert_eq!(ballot.get_voter_count(), 4);
+        }
+
+        #[ink::test]
+        fn get_voter_info_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 3).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+
+            let info = ballot.get_voter_info(accounts.bob).unwrap();
+            let voter = ballot.get_voter(accounts.bob).unwrap();
+            assert_eq!(info.weight, voter.weight);
+            assert_eq!(info.voted, voter.voted);
+            assert_eq!(info.delegate, voter.delegate);
+            assert_eq!(info.vote, voter.vote);
+            assert_eq!(info, VoterInfo { weight: 3, voted: true, delegate: None, vote: Some(0), voted_at: voter.voted_at, delegated_weight: 0 });
+            assert_eq!(ballot.get_voter_info(accounts.charlie), None);
+        }
+
+        #[ink::test]
+        fn voter_getters_work() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.vote(0).unwrap();
+
+            assert_eq!(ballot.has_voted(accounts.alice), Some(true));
+            assert_eq!(ballot.voting_weight_of(accounts.alice), Some(1));
+            assert_eq!(ballot.has_voted(accounts.bob), Some(false));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(0));
+            assert_eq!(ballot.has_voted(accounts.charlie), None);
+            assert_eq!(ballot.voting_weight_of(accounts.charlie), None);
+        }
+
+        #[ink::test]
+        fn voter_counts_work() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            // the chair person is registered with voting right as well
+            assert_eq!(ballot.voter_count(), 4);
+            assert_eq!(ballot.eligible_voter_count(), 3);
+        }
+
+        #[ink::test]
+        fn removing_voter_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_right(accounts.charlie).unwrap();
+
+            assert_eq!(ballot.remove_voter(accounts.bob), Ok(()));
+            assert!(ballot.get_voter(accounts.bob).is_none());
+            assert_eq!(ballot.remove_voter(accounts.bob), Err(Error::NotAVoter));
+            assert_eq!(ballot.remove_voter(accounts.charlie), Err(Error::CannotRemoveActiveVoter));
+            assert_eq!(ballot.voter_count(), 2);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "the chair person cannot be removed")]
+        fn removing_chairperson_fails() {
+            let mut ballot = Ballot::default();
+            let chair = ballot.get_chairperson();
+            let _ = ballot.remove_voter(chair);
+        }
+
+        #[ink::test]
+        fn abstention_count_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            // django is registered but has no right to vote
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.abstention_count(), 2);
+        }
+
+        #[ink::test]
+        fn give_voting_rights_work() {
+            let mut ballot = Ballot::default();
+            let account_id = AccountId::from([0x0; 32]);
+
+            ballot.add_voter(account_id).unwrap();     
+            ballot.give_voting_right(account_id).unwrap();
+            let voter = ballot.get_voter(account_id).unwrap();
+            assert_eq!(voter.weight,1);
+        }
+
+        #[ink::test]
+        fn is_chairperson_works() {
+            let ballot = Ballot::default();
+            let accounts = default_accounts();
+            assert!(ballot.is_chairperson(accounts.alice));
+            assert!(!ballot.is_chairperson(accounts.bob));
+        }
+
+        #[ink::test]
+        fn transfer_chairperson_works() {
+            let mut ballot = Ballot::default();
+            let old_chair = ballot.get_chairperson();
+            let bob = default_accounts().bob;
+
+            ballot.transfer_chairperson(bob);
+            assert_eq!(ballot.get_chairperson(), bob);
+            assert_eq!(ballot.get_voter(bob).unwrap().weight, 0);
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(emitted_events.len(), 1);
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events[0].data[..])
+                .expect("encountered invalid contract event data buffer");
+            if let Event::ChairpersonTransferred(event) = decoded {
+                assert_eq!(event.old_chair, old_chair);
+                assert_eq!(event.new_chair, bob);
+            } else {
+                panic!("encountered unexpected event kind: expected a ChairpersonTransferred event")
+            }
+
+            // the new chair person can now give voting rights
+            set_caller(bob);
+            ballot.give_voting_right(bob).unwrap();
+            assert_eq!(ballot.get_voter(bob).unwrap().weight, 1);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "only chair person can transfer the role")]
+        fn transfer_chairperson_fails_for_non_chairperson() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            set_caller(bob);
+            ballot.transfer_chairperson(bob);
+        }
+
+        #[ink::test]
+        fn give_weighted_voting_right_works() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_weighted_voting_right(bob, 5).unwrap();
+            assert_eq!(ballot.voting_weight_of(bob), Some(5));
+
+            set_caller(bob);
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(5));
+        }
+
+        #[ink::test]
+        fn give_voting_rights_in_batch_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            // the chair person has already voted and django is unknown
+            ballot.vote(0).unwrap();
+
+            ballot.give_voting_rights(vec![
+                accounts.bob,
+                accounts.django,
+                ballot.get_chairperson(),
+                accounts.charlie,
+            ]).unwrap();
+            assert_eq!(ballot.get_voter(accounts.bob).unwrap().weight, 1);
+            assert_eq!(ballot.get_voter(accounts.charlie).unwrap().weight, 1);
+            assert!(ballot.get_voter(accounts.django).is_none());
+
+            let granted = ink_env::test::recorded_events()
+                .filter_map(|event| {
+                    match <Event as scale::Decode>::decode(&mut &event.data[..]) {
+                        Ok(Event::VotingRightGranted(event)) => Some(event.voter),
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(granted, vec![accounts.bob, accounts.charlie]);
+        }
+
+        #[ink::test]
+        fn pausing_blocks_mutations() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+
+            ballot.pause();
+            assert_eq!(ballot.is_paused(), true);
+            assert_eq!(ballot.vote(0), Err(Error::Paused));
+            assert_eq!(ballot.delegate(bob), Err(Error::Paused));
+            assert_eq!(ballot.add_voter(default_accounts().charlie), Err(Error::Paused));
+            assert_eq!(ballot.give_voting_right(bob), Err(Error::Paused));
+            // read-only messages keep working
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+            assert_eq!(ballot.voting_weight_of(bob), Some(0));
+
+            ballot.unpause();
+            assert_eq!(ballot.is_paused(), false);
+            assert_eq!(ballot.give_voting_right(bob), Ok(()));
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn voting_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert_eq!(ballot.vote(0), Ok(()));
+            let voter = ballot.get_voter(ballot.get_chairperson()).unwrap();
+            assert_eq!(voter.voted,true);
+        }
+
+        #[ink::test]
+        fn get_proposal_votes_works() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+            assert_eq!(ballot.get_proposal_votes(1), None);
+        }
+
+        #[ink::test]
+        fn total_votes_cast_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            ballot.vote(0).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(1).unwrap();
+            set_caller(accounts.charlie);
+            ballot.delegate(accounts.bob).unwrap();
+
+            assert_eq!(ballot.total_votes_cast(), 3);
+            assert_eq!(
+                ballot.total_votes_cast(),
+                ballot.get_proposal_votes(0).unwrap() + ballot.get_proposal_votes(1).unwrap()
+            );
+        }
+
+        #[ink::test]
+        fn voting_emits_vote_cast_event() {
+            let names = vec![String::from("Proposal #1"), String::from("Proposal #2")];
+            let mut ballot = Ballot::new(Some(names), None, None, 0, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.vote(0), Err(Error::AlreadyVoted));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(emitted_events.len(), 1);
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events[0].data[..])
+                .expect("encountered invalid contract event data buffer");
+            if let Event::VoteCast(VoteCast { voter, proposal_index, weight }) = decoded {
+                assert_eq!(voter, ballot.get_chairperson());
+                assert_eq!(proposal_index, 0);
+                assert_eq!(weight, 1);
+            } else {
+                panic!("encountered unexpected event kind: expected a VoteCast event")
+            }
+        }
+
+        #[ink::test]
+        fn chair_can_vote_when_allowed() {
+            let names = vec![String::from("Proposal #1")];
+            let mut ballot = Ballot::new(Some(names), None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.voting_weight_of(ballot.get_chairperson()), Some(1));
+            assert_eq!(ballot.vote(0), Ok(()));
+        }
+
+        #[ink::test]
+        fn chair_cannot_vote_when_forbidden() {
+            let names = vec![String::from("Proposal #1")];
+            let mut ballot = Ballot::new(Some(names), None, None, 0, DEFAULT_MAX_NAME_LEN, false, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let chair = ballot.get_chairperson();
+            let bob = default_accounts().bob;
+            ballot.add_voter(bob).unwrap();
+            assert_eq!(ballot.voting_weight_of(chair), Some(0));
+
+            // even with a voting right the chair person stays neutral
+            ballot.give_voting_right(chair).unwrap();
+            assert_eq!(ballot.vote(0), Err(Error::ChairCannotVote));
+            assert_eq!(ballot.delegate(bob), Err(Error::ChairCannotVote));
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+        }
+
+        #[ink::test]
+        fn voting_fails_for_unknown_voter() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            set_caller(default_accounts().bob);
+            assert_eq!(ballot.vote(0), Err(Error::NotAVoter));
+        }
+
+        #[ink::test]
+        fn voting_twice_fails() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.vote(0), Err(Error::AlreadyVoted));
+        }
+
+        #[ink::test]
+        fn voting_without_right_fails() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            set_caller(bob);
+            assert_eq!(ballot.vote(0), Err(Error::NoVotingRight));
+        }
+
+        #[ink::test]
+        fn voting_for_largest_index_fails() {
+            // negative indices can no longer be passed, the largest index,
+            // which `-1` used to wrap around to, is rejected cleanly
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert_eq!(ballot.vote(u32::MAX), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.has_voted(ballot.get_chairperson()), Some(false));
+        }
+
+        #[ink::test]
+        fn voting_for_missing_proposal_fails() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert_eq!(ballot.vote(1), Err(Error::ProposalIndexOutOfBounds));
+            let voter = ballot.get_voter(ballot.get_chairperson()).unwrap();
+            assert_eq!(voter.voted,false);
+        }
+
+        #[ink::test]
+        fn voting_fails_after_deadline() {
+            let mut ballot = Ballot::new(Some(vec![String::from("Proposal #1")]), None, Some(1), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let bob = default_accounts().bob;
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.vote(0), Ok(()));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            set_caller(bob);
+            assert_eq!(ballot.vote(0), Err(Error::VotingClosed));
+            assert_eq!(ballot.delegate(ballot.get_chairperson()), Err(Error::VotingClosed));
+        }
+
+        #[ink::test]
+        fn voting_fails_before_start_block() {
+            let names = vec![String::from("Proposal #1")];
+            let mut ballot = Ballot::new(Some(names), Some(2), None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let bob = default_accounts().bob;
+            ballot.add_voter(bob).unwrap();
+            assert_eq!(ballot.get_start_block(), Some(2));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.vote(0), Err(Error::NotStarted));
+            assert_eq!(ballot.delegate(bob), Err(Error::NotStarted));
+            assert_eq!(ballot.commit_vote([0x0; 32]), Err(Error::NotStarted));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn set_deadline_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert_eq!(ballot.get_deadline(), None);
+            ballot.set_deadline(0).unwrap();
+            assert_eq!(ballot.get_deadline(), Some(0));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.vote(0), Err(Error::VotingClosed));
+        }
+
+        #[ink::test]
+        fn blocks_until_deadline_works() {
+            let mut ballot = Ballot::default();
+            assert_eq!(ballot.blocks_until_deadline(), None);
+
+            ballot.set_deadline(2).unwrap();
+            assert_eq!(ballot.blocks_until_deadline(), Some(2));
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.blocks_until_deadline(), Some(1));
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.blocks_until_deadline(), Some(0));
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "only chair person can set the deadline")]
+        fn set_deadline_fails_for_non_chairperson() {
+            let mut ballot = Ballot::default();
+            set_caller(default_accounts().bob);
+            ballot.set_deadline(10).unwrap();
+        }
+
+        fn commitment_of(proposal_index: u32, salt: u64) -> [u8; 32] {
+            let mut commitment = [0x0; 32];
+            ink_env::hash_bytes::<ink_env::hash::Blake2x256>(
+                &scale::Encode::encode(&(proposal_index, salt)),
+                &mut commitment,
+            );
+            commitment
+        }
+
+        #[ink::test]
+        fn commit_reveal_voting_works() {
+            let names = vec![String::from("Proposal #1"), String::from("Proposal #2")];
+            let mut ballot = Ballot::new(Some(names), None, Some(0), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.commit_vote(commitment_of(1, 42)), Ok(()));
+            assert_eq!(ballot.reveal_vote(1, 42), Err(Error::VotingNotClosed));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.commit_vote(commitment_of(0, 42)), Err(Error::VotingClosed));
+            assert_eq!(ballot.reveal_vote(1, 42), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(1), Some(1));
+            assert_eq!(ballot.reveal_vote(1, 42), Err(Error::AlreadyVoted));
+            assert_eq!(ballot.get_proposal_votes(1), Some(1));
+        }
+
+        #[ink::test]
+        fn reveal_with_wrong_salt_fails() {
+            let names = vec![String::from("Proposal #1"), String::from("Proposal #2")];
+            let mut ballot = Ballot::new(Some(names), None, Some(0), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let bob = default_accounts().bob;
+            ballot.add_voter(bob).unwrap();
+            assert_eq!(ballot.commit_vote(commitment_of(1, 42)), Ok(()));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.reveal_vote(1, 7), Err(Error::CommitmentMismatch));
+            assert_eq!(ballot.reveal_vote(0, 42), Err(Error::CommitmentMismatch));
+            assert_eq!(ballot.get_proposal_votes(1), Some(0));
+            assert_eq!(ballot.has_voted(ballot.get_chairperson()), Some(false));
+
+            set_caller(bob);
+            assert_eq!(ballot.reveal_vote(1, 42), Err(Error::NoCommitment));
+        }
+
+        #[ink::test]
+        fn reset_ballot_works() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+            ballot.vote(0).unwrap();
+            set_caller(bob);
+            ballot.vote(0).unwrap();
+
+            set_caller(ballot.get_chairperson());
+            ballot.reset_ballot();
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+            assert_eq!(ballot.total_votes_cast(), 0);
+            assert_eq!(ballot.has_voted(bob), Some(false));
+            assert_eq!(ballot.voting_weight_of(bob), Some(1));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                .expect("encountered invalid contract event data buffer");
+            assert!(matches!(decoded, Event::BallotReset(_)));
+
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn revoking_vote_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.proposals[0].vote_count, 1);
+
+            assert_eq!(ballot.revoke_vote(), Ok(()));
+            assert_eq!(ballot.proposals[0].vote_count, 0);
+            let voter = ballot.get_voter(ballot.get_chairperson()).unwrap();
+            assert_eq!(voter.voted,false);
+            assert_eq!(voter.vote, None);
+
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.proposals[0].vote_count, 1);
+        }
+
+        #[ink::test]
+        fn changing_vote_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert_eq!(ballot.change_vote(1), Err(Error::HasNotVoted));
+
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.change_vote(2), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.change_vote(1), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+            assert_eq!(ballot.get_proposal_votes(1), Some(1));
+            assert_eq!(ballot.total_votes_cast(), 1);
+            assert_eq!(ballot.get_voter_info(ballot.get_chairperson()).unwrap().vote, Some(1));
+        }
+
+        #[ink::test]
+        fn changing_delegated_vote_fails() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.delegate(bob).unwrap();
+            assert_eq!(ballot.change_vote(0), Err(Error::VoteWasDelegated));
+        }
+
+        #[ink::test]
+        fn revoking_vote_fails_without_direct_vote() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert_eq!(ballot.revoke_vote(), Err(Error::HasNotVoted));
+
+            ballot.add_voter(bob).unwrap();
+            assert_eq!(ballot.delegate(bob), Ok(()));
+            assert_eq!(ballot.revoke_vote(), Err(Error::VoteWasDelegated));
+        }
+
+        #[ink::test]
+        fn delegation_works() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 0, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let to_id = AccountId::from([0x0; 32]);
+
+            ballot.add_voter(to_id).unwrap();     
+            assert_eq!(ballot.delegate(to_id), Ok(()));
+
+            let voter = ballot.get_voter(ballot.get_chairperson()).unwrap();
+            assert_eq!(voter.delegate.unwrap(),to_id);
+            assert!(voter.voted);
+        } 
+
+        #[ink::test]
+        fn delegation_fails_with_typed_errors() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+
+            assert_eq!(ballot.delegate(accounts.alice), Err(Error::SelfDelegation));
+            assert_eq!(ballot.delegate(accounts.charlie), Err(Error::DelegateNotFound));
+            assert!(!ballot.get_voter(accounts.alice).unwrap().voted);
+
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.delegate(accounts.bob), Err(Error::NotAVoter));
+
+            set_caller(accounts.alice);
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.delegate(accounts.bob), Err(Error::AlreadyVoted));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(0));
+        }
+
+        #[ink::test]
+        fn delegate_votes_with_pooled_weight() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+
+            ballot.delegate(bob).unwrap();
+            assert_eq!(ballot.voting_weight_of(bob), Some(2));
+
+            set_caller(bob);
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(2));
+        }
+
+        #[ink::test]
+        fn redelegation_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+
+            ballot.delegate(accounts.bob).unwrap();
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(2));
+
+            assert_eq!(ballot.redelegate(accounts.charlie), Ok(()));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+            assert_eq!(ballot.voting_weight_of(accounts.charlie), Some(2));
+            assert_eq!(ballot.get_voter(accounts.alice).unwrap().delegate, Some(accounts.charlie));
+
+            // a delegate who already voted keeps the votes
+            set_caller(accounts.charlie);
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.get_proposal_votes(0), Some(2));
+            set_caller(accounts.alice);
+            assert_eq!(ballot.redelegate(accounts.bob), Err(Error::DelegateAlreadyVoted));
+            assert_eq!(ballot.get_proposal_votes(0), Some(2));
+            assert_eq!(ballot.total_votes_cast(), 2);
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+        }
+
+        #[ink::test]
+        fn clear_delegation_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+            assert_eq!(ballot.clear_delegation(), Err(Error::NotDelegated));
+
+            ballot.delegate(accounts.bob).unwrap();
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(2));
+            assert_eq!(ballot.clear_delegation(), Ok(()));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+            assert_eq!(ballot.voting_weight_of(accounts.alice), Some(1));
+            assert_eq!(ballot.has_voted(accounts.alice), Some(false));
+            assert_eq!(ballot.get_voter(accounts.alice).unwrap().delegate, None);
+
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn clear_delegation_fails_once_delegate_voted() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+
+            ballot.delegate(accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+
+            set_caller(accounts.alice);
+            assert_eq!(ballot.clear_delegation(), Err(Error::DelegateAlreadyVoted));
+            assert_eq!(ballot.get_proposal_votes(0), Some(2));
+            assert_eq!(ballot.has_voted(accounts.alice), Some(true));
+        }
+
+        #[ink::test]
+        fn redelegation_fails_without_delegation() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            assert_eq!(ballot.redelegate(accounts.bob), Err(Error::NotDelegated));
+
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.redelegate(accounts.bob), Err(Error::AlreadyVoted));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(0));
+        }
+
+        #[ink::test]
+        fn delegation_emits_delegated_event() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            // delegate to a voter who did not vote yet
+            assert_eq!(ballot.delegate(accounts.bob), Ok(()));
+            // delegate to a voter who already voted
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.delegate(accounts.bob), Ok(()));
+
+            let delegations = ink_env::test::recorded_events()
+                .filter(|event| {
+                    matches!(
+                        <Event as scale::Decode>::decode(&mut &event.data[..]),
+                        Ok(Event::Delegated(_))
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(delegations.len(), 2);
+
+            let expected = [
+                (accounts.alice, accounts.bob),
+                (accounts.charlie, accounts.bob),
+            ];
+            for (event, (from, to)) in delegations.iter().zip(expected.iter()) {
+                let decoded = <Event as scale::Decode>::decode(&mut &event.data[..]).unwrap();
+                if let Event::Delegated(delegated) = decoded {
+                    assert_eq!(delegated.weight, 1);
+                }
+                let topics = event.topics
+                    .iter()
+                    .map(|topic| topic.decode::<Hash>().expect("encountered invalid topic encoding"))
+                    .collect::<Vec<_>>();
+                assert_eq!(topics.len(), 3);
+                assert_eq!(topics[1], account_topic(*from));
+                assert_eq!(topics[2], account_topic(*to));
+            }
+        }
+
+        #[ink::test]
+        fn delegation_loop_fails() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 0, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.delegate(accounts.charlie), Ok(()));
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.delegate(accounts.django), Ok(()));
+
+            set_caller(accounts.django);
+            assert_eq!(ballot.delegate(accounts.bob), Err(Error::DelegationLoop));
+
+            let django = ballot.get_voter(accounts.django).unwrap();
+            assert_eq!(django.weight, 3);
+            assert_eq!(django.voted, false);
+            assert_eq!(django.delegate, None);
+            assert_eq!(ballot.get_voter(accounts.bob).unwrap().weight, 1);
+        }
+
+        #[ink::test]
+        fn delegation_follows_chain() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve]).unwrap();
+
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.delegate(accounts.django), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(ballot.delegate(accounts.charlie), Ok(()));
+
+            assert_eq!(ballot.get_voter(accounts.charlie).unwrap().weight, 1);
+            assert_eq!(ballot.get_voter(accounts.django).unwrap().weight, 3);
+            assert_eq!(ballot.get_voter(accounts.bob).unwrap().delegate, Some(accounts.django));
+
+            // when the final delegate already voted, the weight goes to the proposal
+            let chair = ballot.get_chairperson();
+            set_caller(chair);
+            ballot.add_voter(accounts.frank).unwrap();
+            ballot.give_voting_right(accounts.frank).unwrap();
+            set_caller(accounts.eve);
+            assert_eq!(ballot.vote(0), Ok(()));
+            set_caller(chair);
+            assert_eq!(ballot.delegate(accounts.eve), Ok(()));
+            set_caller(accounts.frank);
+            assert_eq!(ballot.delegate(chair), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(3));
+        }
+
+        #[ink::test]
+        fn get_winning_proposal_name_working() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(0).unwrap();
+            let winner = ballot.get_winning_proposal_name();
+            assert_eq!(winner, Some((String::from("Proposal #1"), false)));
+        }
+
+        #[ink::test]
+        fn get_winning_proposal_name_reports_tie() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+            ballot.vote(1).unwrap();
+            set_caller(bob);
+            ballot.vote(0).unwrap();
+
+            let winner = ballot.get_winning_proposal_name();
+            assert_eq!(winner, Some((String::from("Proposal #1"), true)));
+        }
+
+        #[ink::test]
+        fn get_winning_proposal_name_requires_quorum() {
+            let names = vec![String::from("Proposal #1"), String::from("Proposal #2")];
+            let mut ballot = Ballot::new(Some(names), None, None, 3, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            ballot.vote(0).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.has_quorum(), false);
+            assert_eq!(ballot.get_winning_proposal_name(), None);
+
+            set_caller(accounts.charlie);
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.has_quorum(), true);
+            assert_eq!(
+                ballot.get_winning_proposal_name(),
+                Some((String::from("Proposal #1"), false))
+            );
+        }
+
+        #[ink::test]
+        fn approved_proposals_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal_with_threshold(String::from("Proposal #1"), 2).unwrap();
+            ballot.add_proposal_with_threshold(String::from("Proposal #2"), 3).unwrap();
+            ballot.add_proposal_with_threshold(String::from("Proposal #3"), 1).unwrap();
+            ballot.add_proposal(String::from("Proposal #4")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 2).unwrap();
+            ballot.give_weighted_voting_right(accounts.charlie, 2).unwrap();
+
+            ballot.vote(2).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+            set_caller(accounts.charlie);
+            ballot.vote(1).unwrap();
+
+            assert_eq!(ballot.approved_proposals(), vec![0, 2]);
+        }
+
+        #[ink::test]
+        fn all_proposals_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(1).unwrap();
+
+            assert_eq!(ballot.all_proposals(), vec![
+                (0, String::from("Proposal #1"), 0),
+                (1, String::from("Proposal #2"), 1),
+            ]);
+        }
+
+        #[ink::test]
+        fn get_results_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_proposal(String::from("Proposal #3")).unwrap();
+            ballot.add_proposal(String::from("Proposal #4")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 3).unwrap();
+            ballot.give_weighted_voting_right(accounts.charlie, 2).unwrap();
+
+            ballot.vote(1).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+            set_caller(accounts.charlie);
+            ballot.vote(2).unwrap();
+
+            assert_eq!(ballot.get_results(), vec![
+                (String::from("Proposal #1"), 3),
+                (String::from("Proposal #3"), 2),
+                (String::from("Proposal #2"), 1),
+                (String::from("Proposal #4"), 0),
+            ]);
+        }
+
+        #[ink::test]
+        fn winning_proposal_index_works() {
+            let mut ballot = Ballot::default();
+            assert_eq!(ballot.winning_proposal_index(), None);
+
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.winning_proposal_index(), Some(1));
+        }
+
+        /// Stands in for a contract reading the results of a ballot
+        /// only through the `BallotReader` interface.
+        struct ResultsReader<'a, R: BallotReader> {
+            ballot: &'a R,
+        }
+
+        impl<'a, R: BallotReader> ResultsReader<'a, R> {
+            fn winner(&self) -> R::WinningProposalIndexOut {
+                self.ballot.winning_proposal_index()
+            }
+
+            fn votes_of(&self, index: u32) -> R::GetProposalVotesOut {
+                self.ballot.get_proposal_votes(index)
+            }
+        }
+
+        #[ink::test]
+        fn ballot_reader_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(1).unwrap();
+
+            let reader = ResultsReader { ballot: &ballot };
+            assert_eq!(reader.winner(), Some(1));
+            assert_eq!(reader.votes_of(1), Some(1));
+            assert_eq!(reader.votes_of(2), None);
+        }
+
+        #[ink::test]
+        fn get_winning_proposal_name_on_empty_ballot() {
+            let ballot = Ballot::default();
+            assert_eq!(ballot.get_winning_proposal_name(), None);
+        }
+
+        #[ink::test]
+        fn is_voting_open_follows_window() {
+            let names = vec![String::from("Proposal #1")];
+            let mut ballot = Ballot::new(Some(names), Some(1), Some(2), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert!(!ballot.is_voting_open());
+            assert_eq!(ballot.vote(0), Err(Error::NotStarted));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert!(ballot.is_voting_open());
+
+            ballot.pause();
+            assert!(!ballot.is_voting_open());
+            assert_eq!(ballot.vote(0), Err(Error::Paused));
+            ballot.unpause();
+            assert!(ballot.is_voting_open());
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert!(!ballot.is_voting_open());
+            assert_eq!(ballot.vote(0), Err(Error::VotingClosed));
+        }
+
+        #[ink::test]
+        fn is_voting_open_without_window() {
+            let ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 0, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert!(ballot.is_voting_open());
+        }
+
+        #[ink::test]
+        fn vote_records_timestamp() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            let chair = ballot.get_chairperson();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.get_voter_info(chair).unwrap().voted_at, None);
+
+            ballot.vote(0).unwrap();
+            let now = ink_env::block_timestamp::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.get_voter_info(chair).unwrap().voted_at, Some(now));
+
+            ballot.revoke_vote().unwrap();
+            assert_eq!(ballot.get_voter_info(chair).unwrap().voted_at, None);
+        }
+
+        #[ink::test]
+        fn open_registration_allows_anyone_to_add_voters() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            assert!(ballot.is_registration_open());
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.add_voter(accounts.bob), Ok(true));
+            assert_eq!(ballot.add_voter(accounts.charlie), Ok(true));
+        }
+
+        #[ink::test]
+        fn closed_registration_allows_only_chairperson() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.close_registration();
+            assert!(!ballot.is_registration_open());
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.add_voter(accounts.bob), Err(Error::RegistrationClosed));
+            assert_eq!(ballot.add_voters(vec![accounts.charlie]), Err(Error::RegistrationClosed));
+            assert_eq!(ballot.get_voter_info(accounts.bob), None);
+
+            set_caller(accounts.alice);
+            assert_eq!(ballot.add_voter(accounts.bob), Ok(true));
+
+            ballot.open_registration();
+            set_caller(accounts.bob);
+            assert_eq!(ballot.add_voter(accounts.charlie), Ok(true));
+        }
+
+        #[ink::test]
+        fn proposal_exists_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert!(ballot.proposal_exists(String::from("Proposal #1")));
+            assert!(!ballot.proposal_exists(String::from("Proposal #2")));
+            assert!(!ballot.proposal_exists(String::from("proposal #1")));
+        }
+
+        #[ink::test]
+        fn propose_and_vote_works() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            let index = ballot.propose_and_vote(String::from("Write-in")).unwrap();
+            assert_eq!(index, 1);
+            assert_eq!(ballot.get_proposal_votes(index), Some(1));
+            assert_eq!(ballot.get_voter_info(accounts.bob).unwrap().vote, Some(index));
+
+            assert_eq!(ballot.propose_and_vote(String::from("Another")), Err(Error::AlreadyVoted));
+            assert!(!ballot.proposal_exists(String::from("Another")));
+        }
+
+        #[ink::test]
+        fn propose_and_vote_requires_voting_right() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.propose_and_vote(String::from("Write-in")), Err(Error::NoVotingRight));
+            set_caller(accounts.alice);
+            assert_eq!(ballot.propose_and_vote(String::from("Proposal #1")), Err(Error::DuplicateProposal));
+            assert_eq!(ballot.get_proposal_count(), 1);
+        }
+
+        #[ink::test]
+        fn finalize_declares_winner() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.finalize(), Err(Error::VotingNotClosed));
+
+            ballot.lock_proposals();
+            assert_eq!(ballot.finalize(), Ok(()));
+            assert_eq!(ballot.finalized_winner(), Some(1));
+            assert_eq!(ballot.finalize(), Err(Error::AlreadyFinalized));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                .expect("encountered invalid contract event data buffer");
+            match decoded {
+                Event::WinnerDeclared(WinnerDeclared { index, name, votes }) => {
+                    assert_eq!(index, 1);
+                    assert_eq!(name, String::from("Proposal #2"));
+                    assert_eq!(votes, 1);
+                }
+                _ => panic!("encountered unexpected event kind: expected a WinnerDeclared event"),
+            }
+        }
+
+        #[ink::test]
+        fn voting_fails_after_finalize() {
+            let mut ballot = Ballot::new(Some(vec![String::from("Proposal #1")]), None, Some(0), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let bob = default_accounts().bob;
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+
+            assert_eq!(ballot.finalize(), Ok(()));
+            assert_eq!(ballot.finalized_winner(), None);
+            set_caller(bob);
+            assert_eq!(ballot.vote(0), Err(Error::AlreadyFinalized));
+            assert!(!ballot.is_voting_open());
+        }
+
+        #[ink::test]
+        fn voting_requires_min_proposals() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert_eq!(ballot.vote(0), Err(Error::NotEnoughProposals));
+            assert!(!ballot.is_voting_open());
+
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert!(ballot.is_voting_open());
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn chairperson_voter_works() {
+            let mut ballot = Ballot::default();
+            let info = ballot.chairperson_voter();
+            assert_eq!(info.weight, 1);
+            assert!(!info.voted);
+
+            ballot.transfer_chairperson(default_accounts().bob);
+            assert_eq!(Some(ballot.chairperson_voter()), ballot.get_voter_info(default_accounts().bob));
+        }
+
+        #[ink::test]
+        fn ranked_choice_can_differ_from_plurality() {
+            let names = vec![String::from("A"), String::from("B"), String::from("C")];
+            let mut ballot = Ballot::new(Some(names), None, None, 0, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::RankedChoice, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 4).unwrap();
+            ballot.give_weighted_voting_right(accounts.charlie, 3).unwrap();
+            ballot.give_weighted_voting_right(accounts.django, 2).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote_ranked(vec![0]), Ok(()));
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.vote_ranked(vec![1, 2]), Ok(()));
+            set_caller(accounts.django);
+            assert_eq!(ballot.vote_ranked(vec![2, 1]), Ok(()));
+            assert_eq!(ballot.get_ranking(accounts.django), Some(vec![2, 1]));
+
+            // A leads on first choices but C's votes move to B
+            assert_eq!(ballot.leading_proposal(), Some((0, 4)));
+            assert_eq!(ballot.compute_irv_winner(), Some(1));
+            assert_eq!(ballot.winning_proposal_index(), Some(1));
+            assert_eq!(ballot.get_winning_proposal_name(), Some((String::from("B"), false)));
+            assert_eq!(ballot.final_result().winner_index, Some(1));
+            assert_eq!(ballot.final_result().winner_votes, 3);
+        }
+
+        #[ink::test]
+        fn ranked_choice_rejects_invalid_votes() {
+            let names = vec![String::from("A"), String::from("B")];
+            let mut ballot = Ballot::new(Some(names), None, None, 0, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::RankedChoice, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.vote(0), Err(Error::WrongVotingMode));
+            assert_eq!(ballot.vote_ranked(vec![]), Err(Error::InvalidRanking));
+            assert_eq!(ballot.vote_ranked(vec![0, 0]), Err(Error::InvalidRanking));
+            assert_eq!(ballot.vote_ranked(vec![0, 2]), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.compute_irv_winner(), None);
+
+            assert_eq!(ballot.vote_ranked(vec![1, 0]), Ok(()));
+            assert_eq!(ballot.compute_irv_winner(), Some(1));
+            assert_eq!(ballot.revoke_vote(), Err(Error::WrongVotingMode));
+
+            let mut plurality = Ballot::default();
+            assert_eq!(plurality.vote_ranked(vec![0]), Err(Error::NotEnoughProposals));
+            plurality.add_proposal(String::from("A")).unwrap();
+            plurality.add_proposal(String::from("B")).unwrap();
+            assert_eq!(plurality.vote_ranked(vec![0]), Err(Error::WrongVotingMode));
+        }
+
+        #[ink::test]
+        fn vote_count_overflow_is_rejected() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_weighted_voting_right(bob, u32::MAX).unwrap();
+
+            set_caller(bob);
+            assert_eq!(ballot.vote(0), Ok(()));
+            set_caller(ballot.get_chairperson());
+            assert_eq!(ballot.vote(0), Err(Error::Overflow));
+            assert_eq!(ballot.vote(1), Err(Error::Overflow));
+            assert_eq!(ballot.has_voted(ballot.get_chairperson()), Some(false));
+            assert_eq!(ballot.get_proposal_votes(0), Some(u32::MAX));
+            assert_eq!(ballot.total_votes_cast(), u32::MAX);
+        }
+
+        #[ink::test]
+        fn delegation_overflow_is_rejected() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_weighted_voting_right(bob, u32::MAX).unwrap();
+
+            assert_eq!(ballot.delegate(bob), Err(Error::Overflow));
+            assert_eq!(ballot.has_voted(ballot.get_chairperson()), Some(false));
+            assert_eq!(ballot.voting_weight_of(bob), Some(u32::MAX));
+        }
+
+        #[ink::test]
+        fn delegation_to_voted_delegate_overflow_is_rejected() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_weighted_voting_right(bob, u32::MAX - 1).unwrap();
+            set_caller(bob);
+            ballot.vote(0).unwrap();
+            set_caller(ballot.get_chairperson());
+            ballot.give_weighted_voting_right(ballot.get_chairperson(), 2).unwrap();
+
+            // the vote count of the proposal bob voted for would overflow
+            assert_eq!(ballot.delegate(bob), Err(Error::Overflow));
+            assert_eq!(ballot.has_voted(ballot.get_chairperson()), Some(false));
+            assert_eq!(ballot.get_proposal_votes(0), Some(u32::MAX - 1));
+            assert_eq!(ballot.total_votes_cast(), u32::MAX - 1);
+            assert_eq!(ballot.get_voter_info(bob).unwrap().delegated_weight, 0);
+        }
+
+        #[ink::test]
+        fn revoke_voting_right_works() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+
+            assert_eq!(ballot.revoke_voting_right(bob), Ok(()));
+            assert_eq!(ballot.voting_weight_of(bob), Some(0));
+            assert_eq!(ballot.revoke_voting_right(default_accounts().charlie), Err(Error::NotAVoter));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let event = emitted_events.last().unwrap();
+            let decoded = <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer");
+            match decoded {
+                Event::VotingRightRevoked(VotingRightRevoked { voter }) => assert_eq!(voter, bob),
+                _ => panic!("encountered unexpected event kind: expected a VotingRightRevoked event"),
+            }
+            assert_eq!(event.topics[1].decode::<Hash>().unwrap(), account_topic(bob));
+
+            set_caller(bob);
+            assert_eq!(ballot.vote(0), Err(Error::NoVotingRight));
+        }
+
+        #[ink::test]
+        fn revoke_voting_right_fails_after_vote() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+
+            set_caller(bob);
+            ballot.vote(0).unwrap();
+            set_caller(default_accounts().alice);
+            assert_eq!(ballot.revoke_voting_right(bob), Err(Error::AlreadyVoted));
+            assert_eq!(ballot.voting_weight_of(bob), Some(1));
+        }
+
+        #[ink::test]
+        fn participation_bps_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            assert_eq!(ballot.participation_bps(), 0);
+
+            ballot.vote(0).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.eligible_voter_count(), 4);
+            assert_eq!(ballot.participation_bps(), 5000);
+        }
+
+        #[ink::test]
+        fn participation_bps_without_eligible_voters() {
+            let ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, false, 2, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.eligible_voter_count(), 0);
+            assert_eq!(ballot.participation_bps(), 0);
+        }
+
+        #[ink::test]
+        fn extend_deadline_works() {
+            let names = vec![String::from("Proposal #1")];
+            let mut ballot = Ballot::new(Some(names), None, Some(1), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.extend_deadline(3), Ok(()));
+            assert_eq!(ballot.get_deadline(), Some(3));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.vote(0), Ok(()));
+        }
+
+        #[ink::test]
+        fn extend_deadline_cannot_shrink() {
+            let mut ballot = Ballot::new(None, None, Some(5), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.extend_deadline(4), Err(Error::DeadlineCannotShrink));
+            assert_eq!(ballot.extend_deadline(5), Err(Error::DeadlineCannotShrink));
+            assert_eq!(ballot.get_deadline(), Some(5));
+
+            let mut open_ended = Ballot::default();
+            assert_eq!(open_ended.extend_deadline(10), Err(Error::NoDeadline));
+            assert_eq!(open_ended.get_deadline(), None);
+        }
+
+        #[ink::test]
+        fn extend_deadline_fails_for_past_block() {
+            let mut ballot = Ballot::new(None, None, Some(1), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            for _ in 0..3 {
+                ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            }
+            assert_eq!(ballot.extend_deadline(2), Err(Error::DeadlineInPast));
+            assert_eq!(ballot.extend_deadline(3), Ok(()));
+            assert_eq!(ballot.get_deadline(), Some(3));
+        }
+
+        #[ink::test]
+        fn merge_proposals_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2 (copy)")).unwrap();
+            ballot.add_proposal(String::from("Proposal #3")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            ballot.vote(1).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(2).unwrap();
+            set_caller(accounts.charlie);
+            ballot.vote(3).unwrap();
+
+            set_caller(accounts.alice);
+            assert_eq!(ballot.merge_proposals(1, 1), Err(Error::SameProposal));
+            assert_eq!(ballot.merge_proposals(1, 4), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.merge_proposals(1, 2), Ok(()));
+            assert_eq!(ballot.get_proposal_count(), 3);
+            assert_eq!(ballot.get_proposal_votes(1), Some(2));
+            assert_eq!(ballot.get_proposal_votes(2), Some(1));
+            assert_eq!(ballot.get_voter_info(accounts.bob).unwrap().vote, Some(1));
+            assert_eq!(ballot.get_voter_info(accounts.charlie).unwrap().vote, Some(2));
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.revoke_vote(), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(1), Some(1));
+        }
+
+        #[ink::test]
+        fn chairperson_history_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            assert_eq!(ballot.chairperson_history(), Vec::<AccountId>::new());
+
+            ballot.transfer_chairperson(accounts.bob);
+            set_caller(accounts.bob);
+            ballot.transfer_chairperson(accounts.charlie);
+
+            assert_eq!(ballot.chairperson_history(), vec![accounts.alice, accounts.bob]);
+            assert_eq!(ballot.get_chairperson(), accounts.charlie);
+        }
+
+        #[ink::test]
+        fn proposal_uri_works() {
+            let mut ballot = Ballot::default();
+            let cid = String::from("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert_eq!(ballot.add_proposal_with_uri(String::from("Proposal #2"), String::new()), Err(Error::ProposalUriInvalid));
+            assert_eq!(ballot.add_proposal_with_uri(String::from("Proposal #2"), cid.clone()), Ok(()));
+
+            assert_eq!(ballot.get_proposal_uri(0), None);
+            assert_eq!(ballot.get_proposal_uri(1), Some(cid));
+            assert_eq!(ballot.get_proposal_uri(2), None);
+        }
+
+        #[ink::test]
+        fn voters_for_proposal_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            ballot.vote(1).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(1).unwrap();
+            set_caller(accounts.charlie);
+            ballot.vote(0).unwrap();
+
+            let supporters = ballot.voters_for_proposal(1);
+            assert_eq!(supporters.len(), 2);
+            assert!(supporters.contains(&accounts.alice));
+            assert!(supporters.contains(&accounts.bob));
+            assert_eq!(ballot.voters_for_proposal(0), vec![accounts.charlie]);
+            assert_eq!(ballot.voters_for_proposal(2), Vec::<AccountId>::new());
+        }
+
+        /// Builds a ballot where the first two of three proposals are tied.
+        fn tied_ballot(tie_break: TieBreak) -> Ballot {
+            let names = vec![String::from("Proposal #1"), String::from("Proposal #2"), String::from("Proposal #3")];
+            let mut ballot = Ballot::new(Some(names), None, None, 0, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::Plurality, tie_break, 0, String::new(), String::new());
+            let bob = default_accounts().bob;
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+            ballot.vote(0).unwrap();
+            set_caller(bob);
+            ballot.vote(1).unwrap();
+            set_caller(default_accounts().alice);
+            ballot
+        }
+
+        #[ink::test]
+        fn tie_break_lowest_index() {
+            let ballot = tied_ballot(TieBreak::LowestIndex);
+            assert_eq!(ballot.winning_proposal_index(), Some(0));
+            assert_eq!(ballot.get_winning_proposal_name(), Some((String::from("Proposal #1"), true)));
+        }
+
+        #[ink::test]
+        fn tie_break_highest_index() {
+            let ballot = tied_ballot(TieBreak::HighestIndex);
+            assert_eq!(ballot.winning_proposal_index(), Some(1));
+            assert_eq!(ballot.get_winning_proposal_name(), Some((String::from("Proposal #2"), true)));
+        }
+
+        #[ink::test]
+        fn tie_break_none() {
+            let mut ballot = tied_ballot(TieBreak::None);
+            assert_eq!(ballot.winning_proposal_index(), None);
+            assert_eq!(ballot.get_winning_proposal_name(), None);
+
+            ballot.add_voter(default_accounts().charlie).unwrap();
+            ballot.give_voting_right(default_accounts().charlie).unwrap();
+            set_caller(default_accounts().charlie);
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.winning_proposal_index(), Some(1));
+        }
+
+        #[ink::test]
+        fn remaining_voters_works() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, false, 0, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+
+            let remaining = ballot.remaining_voters();
+            assert_eq!(remaining.len(), 2);
+            assert!(remaining.contains(&accounts.charlie));
+            assert!(remaining.contains(&accounts.django));
+            assert_eq!(remaining.len() as u32, ballot.abstention_count());
+        }
+
+        #[ink::test]
+        fn delegation_to_stale_vote_fails() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+
+            set_caller(bob);
+            ballot.vote(1).unwrap();
+            // simulate a vote left pointing past the proposals
+            ballot.voters.get_mut(&bob).unwrap().vote = Some(2);
+
+            set_caller(default_accounts().alice);
+            assert_eq!(ballot.delegate(bob), Err(Error::StaleVoteIndex));
+            assert_eq!(ballot.has_voted(default_accounts().alice), Some(false));
+            assert_eq!(ballot.total_votes_cast(), 1);
+        }
+
+        #[ink::test]
+        fn proposals_with_zero_votes_works() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_proposal(String::from("Proposal #3")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+            assert_eq!(ballot.proposals_with_zero_votes(), vec![0, 1, 2]);
+
+            ballot.vote(0).unwrap();
+            set_caller(bob);
+            ballot.vote(2).unwrap();
+            assert_eq!(ballot.proposals_with_zero_votes(), vec![1]);
+        }
+
+        #[ink::test]
+        fn max_proposals_is_enforced() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::Plurality, TieBreak::LowestIndex, 2, String::new(), String::new());
+            assert_eq!(ballot.max_proposals(), 2);
+            assert_eq!(ballot.add_proposal(String::from("Proposal #1")), Ok(()));
+            assert_eq!(ballot.add_proposal(String::from("Proposal #2")), Ok(()));
+            assert_eq!(ballot.add_proposal(String::from("Proposal #3")), Err(Error::TooManyProposals));
+            assert_eq!(ballot.propose_and_vote(String::from("Proposal #3")), Err(Error::TooManyProposals));
+            assert_eq!(ballot.get_proposal_count(), 2);
+        }
+
+        #[ink::test]
+        fn delegated_weight_is_tracked() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 2).unwrap();
+            ballot.give_voting_right(accounts.charlie).unwrap();
+
+            ballot.delegate(accounts.charlie).unwrap();
+            set_caller(accounts.bob);
+            ballot.delegate(accounts.charlie).unwrap();
+            let charlie = ballot.get_voter_info(accounts.charlie).unwrap();
+            assert_eq!(charlie.delegated_weight, 3);
+            assert_eq!(charlie.weight, 4);
+
+            assert_eq!(ballot.clear_delegation(), Ok(()));
+            assert_eq!(ballot.get_voter_info(accounts.charlie).unwrap().delegated_weight, 1);
+            assert_eq!(ballot.get_voter_info(accounts.alice).unwrap().delegated_weight, 0);
+        }
+
+        #[ink::test]
+        fn my_voter_info_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 2).unwrap();
+
+            assert_eq!(ballot.my_voter_info(), ballot.get_voter_info(accounts.alice));
+            set_caller(accounts.bob);
+            assert_eq!(ballot.my_voter_info().unwrap().weight, 2);
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.my_voter_info(), None);
+        }
+
+        #[ink::test]
+        fn new_with_descriptions_works() {
+            let ballot = Ballot::new_with_descriptions(vec![
+                (String::from("Proposal #1"), String::from("First")),
+                (String::from("Proposal #2"), String::from("Second")),
+            ]);
+            assert_eq!(ballot.get_proposal_count(), 2);
+            assert_eq!(ballot.get_proposal_name_at_index(1), "Proposal #2");
+            assert_eq!(ballot.get_proposal_description(0), Some(String::from("First")));
+            assert_eq!(ballot.get_proposal_description(1), Some(String::from("Second")));
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "proposal names must be unique")]
+        fn new_with_descriptions_rejects_duplicates() {
+            Ballot::new_with_descriptions(vec![
+                (String::from("Proposal #1"), String::from("First")),
+                (String::from("Proposal #1"), String::from("Again")),
+            ]);
+        }
+
+        #[ink::test]
+        fn leading_proposal_matches_full_scan() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            for name in ["Proposal #1", "Proposal #2", "Proposal #3"].iter() {
+                ballot.add_proposal(String::from(*name)).unwrap();
+            }
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            assert_eq!(ballot.leading_proposal(), None);
+
+            let rescan = |ballot: &Ballot| {
+                let mut leader: Option<(u32, u32)> = None;
+                for (index, _, votes) in ballot.all_proposals() {
+                    if votes > 0 && leader.is_none_or(|(_, most)| votes > most) {
+                        leader = Some((index, votes));
+                    }
+                }
+                leader
+            };
+
+            let steps = [(accounts.bob, 2), (accounts.charlie, 1), (accounts.alice, 1)];
+            for (voter, index) in steps.iter() {
+                set_caller(*voter);
+                ballot.vote(*index).unwrap();
+                assert_eq!(ballot.leading_proposal(), rescan(&ballot));
+            }
+            assert_eq!(ballot.leading_proposal(), Some((1, 2)));
+
+            set_caller(accounts.django);
+            ballot.delegate(accounts.bob).unwrap();
+            assert_eq!(ballot.leading_proposal(), rescan(&ballot));
+            assert_eq!(ballot.leading_proposal(), Some((1, 2)));
+
+            set_caller(accounts.charlie);
+            ballot.change_vote(2).unwrap();
+            assert_eq!(ballot.leading_proposal(), rescan(&ballot));
+            assert_eq!(ballot.leading_proposal(), Some((2, 3)));
+            assert_eq!(ballot.winning_proposal_index(), Some(2));
+        }
+
         #[ink::test]
-        fn new_works() {
-            let mut proposal_names: Vec<String> = Vec::new();
-            proposal_names.push(String::from("Proposal # 1"));  
-            let ballot = Ballot::new(Some(proposal_names));
-            assert_eq!(ballot.get_proposal_count(),1);
+        fn seed_votes_works() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+
+            assert_eq!(ballot.seed_votes(1, 40), Ok(()));
+            assert_eq!(ballot.seed_votes(0, 2), Ok(()));
+            assert_eq!(ballot.seed_votes(2, 1), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.get_proposal_votes(0), Some(2));
+            assert_eq!(ballot.get_proposal_votes(1), Some(40));
+            assert_eq!(ballot.total_votes_cast(), 42);
+            assert_eq!(ballot.leading_proposal(), Some((1, 40)));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                .expect("encountered invalid contract event data buffer");
+            match decoded {
+                Event::VotesSeeded(VotesSeeded { index, count }) => assert_eq!((index, count), (0, 2)),
+                _ => panic!("encountered unexpected event kind: expected a VotesSeeded event"),
+            }
         }
 
         #[ink::test]
-        fn default_works() {
-            let ballot = Ballot::default();
+        fn seed_votes_fails_once_voting_started() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(0).unwrap();
+
+            assert_eq!(ballot.seed_votes(1, 10), Err(Error::SeedingClosed));
+            assert_eq!(ballot.get_proposal_votes(1), Some(0));
+        }
+
+        #[ink::test]
+        fn config_matches_constructor() {
+            let ballot = Ballot::new(None, Some(2), Some(10), 3, DEFAULT_MAX_NAME_LEN, false, 2, VotingMode::Plurality, TieBreak::HighestIndex, 5, String::new(), String::new());
+            assert_eq!(ballot.config(), BallotConfig {
+                quorum: 3,
+                deadline: Some(10),
+                start_block: Some(2),
+                max_proposals: 5,
+                chair_can_vote: false,
+                tie_break: TieBreak::HighestIndex,
+            });
+            assert_eq!(ballot.contract_version(), String::from("0.1.0"));
+        }
+
+        #[ink::test]
+        fn delegation_to_unknown_voter_leaves_state_unchanged() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+
+            assert_eq!(ballot.delegate(accounts.eve), Err(Error::DelegateNotFound));
+            let alice = ballot.get_voter_info(accounts.alice).unwrap();
+            assert!(!alice.voted);
+            assert_eq!(alice.delegate, None);
+            assert_eq!(alice.weight, 1);
+            assert_eq!(ballot.get_voter_info(accounts.eve), None);
+
+            assert_eq!(ballot.vote(0), Ok(()));
+        }
+
+        #[ink::test]
+        fn adding_proposals_emits_proposal_added_events() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert!(ballot.add_proposal(String::from("Proposal #2")).is_err());
+
+            let added = ink_env::test::recorded_events()
+                .filter_map(|event| match <Event as scale::Decode>::decode(&mut &event.data[..]) {
+                    Ok(Event::ProposalAdded(ProposalAdded { index, name })) => Some((index, name)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(added, vec![
+                (0, String::from("Proposal #1")),
+                (1, String::from("Proposal #2")),
+            ]);
+        }
+
+        #[ink::test]
+        fn runner_up_works() {
+            let mut ballot = Ballot::default();
+            for name in ["Proposal #1", "Proposal #2", "Proposal #3"].iter() {
+                ballot.add_proposal(String::from(*name)).unwrap();
+            }
+            ballot.seed_votes(0, 1).unwrap();
+            ballot.seed_votes(1, 3).unwrap();
+            ballot.seed_votes(2, 2).unwrap();
+            assert_eq!(ballot.runner_up(), Some((String::from("Proposal #3"), 2)));
+
+            ballot.seed_votes(0, 1).unwrap();
+            assert_eq!(ballot.runner_up(), None);
+        }
+
+        #[ink::test]
+        fn runner_up_needs_two_proposals() {
+            let mut ballot = Ballot::default();
+            assert_eq!(ballot.runner_up(), None);
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.seed_votes(0, 1).unwrap();
+            assert_eq!(ballot.runner_up(), None);
+        }
+
+        #[ink::test]
+        fn voting_right_expires() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_right_until(accounts.bob, 1).unwrap();
+            ballot.give_voting_right_until(accounts.charlie, 1).unwrap();
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote(0), Ok(()));
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.vote(0), Err(Error::NoVotingRight));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+
+            set_caller(accounts.alice);
+            ballot.give_voting_right(accounts.charlie).unwrap();
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.vote(0), Ok(()));
+        }
+
+        #[ink::test]
+        fn export_matches_stored_state() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 3).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(1).unwrap();
+
+            assert_eq!(ballot.export_results(), vec![
+                (String::from("Proposal #1"), 0),
+                (String::from("Proposal #2"), 3),
+            ]);
+            let voters = ballot.export_voters();
+            assert_eq!(voters.len(), 3);
+            for (voter_id, weight, voted) in voters {
+                let voter = ballot.get_voter_info(voter_id).unwrap();
+                assert_eq!((weight, voted), (voter.weight, voter.voted));
+            }
+            assert!(ballot.export_voters().contains(&(accounts.bob, 3, true)));
+        }
+
+        #[ink::test]
+        fn display_order_works() {
+            let mut ballot = Ballot::default();
+            for name in ["Proposal #1", "Proposal #2", "Proposal #3"].iter() {
+                ballot.add_proposal(String::from(*name)).unwrap();
+            }
+            ballot.vote(0).unwrap();
+
+            assert_eq!(ballot.set_display_order(vec![2, 1, 0]), Ok(()));
+            assert_eq!(ballot.ordered_proposals(), vec![
+                (String::from("Proposal #3"), 0),
+                (String::from("Proposal #2"), 0),
+                (String::from("Proposal #1"), 1),
+            ]);
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn display_order_must_be_permutation() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+
+            assert_eq!(ballot.set_display_order(vec![0]), Err(Error::InvalidDisplayOrder));
+            assert_eq!(ballot.set_display_order(vec![1, 1]), Err(Error::InvalidDisplayOrder));
+            assert_eq!(ballot.set_display_order(vec![0, 2]), Err(Error::InvalidDisplayOrder));
+            assert_eq!(ballot.ordered_proposals(), ballot.export_results());
+        }
+
+        #[ink::test]
+        fn can_vote_checks_eligibility() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+            // not enough proposals yet
+            assert!(!ballot.can_vote(accounts.bob));
+
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert!(ballot.can_vote(accounts.bob));
+            assert!(!ballot.can_vote(accounts.charlie));
+            assert!(!ballot.can_vote(accounts.django));
+
+            ballot.pause();
+            assert!(!ballot.can_vote(accounts.bob));
+            ballot.unpause();
+
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+            assert!(!ballot.can_vote(accounts.bob));
+        }
+
+        #[ink::test]
+        fn can_vote_respects_window_and_chair_rule() {
+            let names = vec![String::from("Proposal #1")];
+            let ballot = Ballot::new(Some(names), Some(1), None, 0, DEFAULT_MAX_NAME_LEN, false, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert!(!ballot.can_vote(default_accounts().alice));
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert!(!ballot.can_vote(default_accounts().alice));
+
+            let names = vec![String::from("Proposal #1")];
+            let ballot = Ballot::new(Some(names), Some(1), None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert!(ballot.can_vote(default_accounts().alice));
+        }
+
+        #[ink::test]
+        fn my_receipt_works() {
+            let mut ballot = Ballot::default();
+            let bob = default_accounts().bob;
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(bob).unwrap();
+            ballot.give_voting_right(bob).unwrap();
+            assert_eq!(ballot.my_receipt(), None);
+
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.my_receipt(), Some((1, 1)));
+
+            set_caller(bob);
+            ballot.delegate(default_accounts().alice).unwrap();
+            assert_eq!(ballot.my_receipt(), None);
+        }
+
+        #[ink::test]
+        fn clear_voters_keeps_only_chairperson() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_weighted_voting_right(accounts.bob, 3).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(1).unwrap();
+            set_caller(accounts.alice);
+            ballot.vote(1).unwrap();
+
+            ballot.clear_voters();
+            assert_eq!(ballot.voter_count(), 1);
+            assert_eq!(ballot.get_voter_info(accounts.bob), None);
+            assert_eq!(ballot.get_voter_info(accounts.charlie), None);
+            let chair = ballot.chairperson_voter();
+            assert_eq!((chair.weight, chair.voted), (1, false));
+            assert_eq!(ballot.get_proposal_votes(1), Some(0));
+            assert_eq!(ballot.total_votes_cast(), 0);
+            assert_eq!(ballot.leading_proposal(), None);
+        }
+
+        #[ink::test]
+        fn delegation_cap_is_enforced() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django, accounts.frank]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.set_max_delegated_weight(2);
+            assert_eq!(ballot.max_delegated_weight(), 2);
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.delegate(accounts.frank), Ok(()));
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.delegate(accounts.frank), Ok(()));
+            set_caller(accounts.django);
+            assert_eq!(ballot.delegate(accounts.frank), Err(Error::DelegationCapExceeded));
+            assert_eq!(ballot.has_voted(accounts.django), Some(false));
+            assert_eq!(ballot.voting_weight_of(accounts.frank), Some(2));
+        }
+
+        #[ink::test]
+        fn inactive_proposal_rejects_votes() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+
+            assert_eq!(ballot.set_proposal_active(0, false), Ok(()));
+            assert_eq!(ballot.is_proposal_active(0), Some(false));
+            assert_eq!(ballot.set_proposal_active(2, false), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.vote(0), Err(Error::ProposalInactive));
+            assert_eq!(ballot.vote(1), Ok(()));
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote(0), Err(Error::ProposalInactive));
+            set_caller(accounts.alice);
+            ballot.set_proposal_active(0, true).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn victory_margin_works() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            assert_eq!(ballot.victory_margin(), None);
+
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            assert_eq!(ballot.victory_margin(), Some(0));
+            ballot.seed_votes(0, 3).unwrap();
+            ballot.seed_votes(1, 5).unwrap();
+            assert_eq!(ballot.victory_margin(), Some(2));
+        }
+
+        #[ink::test]
+        fn proposal_rank_shares_ties() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_proposal(String::from("Proposal #3")).unwrap();
+            ballot.seed_votes(0, 3).unwrap();
+            ballot.seed_votes(1, 1).unwrap();
+            ballot.seed_votes(2, 3).unwrap();
+
+            assert_eq!(ballot.proposal_rank(0), Some(1));
+            assert_eq!(ballot.proposal_rank(2), Some(1));
+            assert_eq!(ballot.proposal_rank(1), Some(3));
+            assert_eq!(ballot.proposal_rank(3), None);
+        }
+
+        #[ink::test]
+        fn metadata_can_be_set() {
+            let ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::from("Board election"), String::from("Elect the new board"));
+            assert_eq!(ballot.title(), String::from("Board election"));
+            assert_eq!(ballot.description(), String::from("Elect the new board"));
+
+            let mut ballot = Ballot::default();
+            assert_eq!(ballot.title(), String::new());
+            ballot.set_metadata(String::from("Budget"), String::from("Pick the budget for next year"));
+            assert_eq!(ballot.title(), String::from("Budget"));
+            assert_eq!(ballot.description(), String::from("Pick the budget for next year"));
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "only chair person can set the metadata")]
+        fn set_metadata_only_by_chairperson() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            set_caller(accounts.bob);
+            ballot.set_metadata(String::from("Budget"), String::new());
+        }
+
+        #[ink::test]
+        fn all_voters_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            let mut voters = ballot.all_voters();
+            voters.sort();
+            let mut expected = vec![accounts.alice, accounts.bob, accounts.charlie];
+            expected.sort();
+            assert_eq!(voters, expected);
+        }
+
+        #[ink::test]
+        fn delegations_works() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+            assert_eq!(ballot.delegations(), vec![]);
+
+            set_caller(accounts.bob);
+            ballot.delegate(accounts.charlie).unwrap();
+            assert_eq!(ballot.delegations(), vec![(accounts.bob, accounts.charlie)]);
+        }
+
+        #[ink::test]
+        fn auto_finalize_on_quorum() {
+            let mut ballot = Ballot::new(None, None, None, 2, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.set_auto_finalize(true);
+            assert!(ballot.auto_finalize());
+
+            set_caller(accounts.bob);
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.finalized_winner(), None);
+
+            // this vote reaches the quorum of 2
+            set_caller(accounts.alice);
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.finalized_winner(), Some(1));
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.vote(0), Err(Error::AlreadyFinalized));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                .expect("encountered invalid contract event data buffer");
+            match decoded {
+                Event::WinnerDeclared(WinnerDeclared { index, votes, .. }) => {
+                    assert_eq!(index, 1);
+                    assert_eq!(votes, 2);
+                }
+                _ => panic!("encountered unexpected event kind: expected a WinnerDeclared event"),
+            }
+        }
+
+        #[ink::test]
+        fn min_weight_to_propose_is_enforced() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, true, 0, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_right(accounts.charlie).unwrap();
+            ballot.set_min_weight_to_propose(1);
+            assert_eq!(ballot.min_weight_to_propose(), 1);
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.propose_and_vote(String::from("Proposal #1")), Err(Error::InsufficientWeight));
             assert_eq!(ballot.get_proposal_count(), 0);
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.propose_and_vote(String::from("Proposal #1")), Ok(0));
+
+            // the chair person is exempt
+            set_caller(accounts.alice);
+            ballot.set_min_weight_to_propose(5);
+            assert_eq!(ballot.propose_and_vote(String::from("Proposal #2")), Ok(1));
         }
 
         #[ink::test]
-        fn adding_proposals_works() {
+        fn is_registered_works() {
             let mut ballot = Ballot::default();
-            ballot.add_proposal(String::from("Proposal #1"));
-            assert_eq!(ballot.get_proposal_count(),1);
+            let accounts = default_accounts();
+            ballot.add_voter(accounts.bob).unwrap();
+
+            assert!(ballot.is_registered(accounts.alice));
+            assert!(ballot.is_registered(accounts.bob));
+            assert!(!ballot.can_vote(accounts.bob));
+            assert!(!ballot.is_registered(accounts.charlie));
         }
 
         #[ink::test]
-        fn adding_voters_work() {
+        fn delegation_depth_is_limited() {
             let mut ballot = Ballot::default();
-            let account_id = AccountId::from([0x0; 32]);
-            assert_eq!(ballot.add_voter(account_id),true);
-            assert_eq!(ballot.add_voter(account_id),false);
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.eve]).unwrap();
+            ballot.set_max_delegation_depth(1);
+            assert_eq!(ballot.max_delegation_depth(), 1);
+
+            // builds the chain bob -> charlie -> django
+            set_caller(accounts.bob);
+            ballot.delegate(accounts.charlie).unwrap();
+            set_caller(accounts.charlie);
+            ballot.delegate(accounts.django).unwrap();
+
+            // charlie forwards once to django
+            set_caller(accounts.alice);
+            assert_eq!(ballot.delegate(accounts.charlie), Ok(()));
+            // bob forwards to charlie who forwards to django
+            set_caller(accounts.eve);
+            assert_eq!(ballot.delegate(accounts.bob), Err(Error::DelegationTooDeep));
+            assert_eq!(ballot.has_voted(accounts.eve), Some(false));
         }
 
         #[ink::test]
-        fn give_voting_rights_work() {
+        fn snapshot_block_freezes_electorate() {
             let mut ballot = Ballot::default();
-            let account_id = AccountId::from([0x0; 32]);
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.set_snapshot_block(0);
+            assert_eq!(ballot.snapshot_block(), Some(0));
 
-            ballot.add_voter(account_id);     
-            ballot.give_voting_right(account_id);
-            let voter = ballot.get_voter(account_id).unwrap();
-            assert_eq!(voter.weight,1);
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            ballot.add_voter(accounts.charlie).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            assert!(ballot.can_vote(accounts.bob));
+            assert!(!ballot.can_vote(accounts.charlie));
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote(0), Ok(()));
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.vote(0), Err(Error::NoVotingRight));
         }
 
         #[ink::test]
-        fn voting_works() {
+        fn unvoted_eligible_weight_works() {
+            let mut ballot = Ballot::new(None, None, None, 0, DEFAULT_MAX_NAME_LEN, false, 2, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+            ballot.give_weighted_voting_right(accounts.charlie, 3).unwrap();
+
+            assert_eq!(ballot.unvoted_eligible_weight(), 4);
+        }
+
+        #[ink::test]
+        fn vote_blank_works() {
             let mut ballot = Ballot::default();
-            ballot.add_proposal(String::from("Proposal #1"));
-            ballot.vote(0);
-            let voter = ballot.get_voter(ballot.get_chairperson()).unwrap();
-            assert_eq!(voter.voted,true);
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote_blank(), Ok(()));
+            assert_eq!(ballot.vote_blank(), Err(Error::AlreadyVoted));
+            assert_eq!(ballot.has_voted(accounts.bob), Some(true));
+            assert_eq!(ballot.blank_vote_count(), 1);
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+            assert_eq!(ballot.get_proposal_votes(1), Some(0));
+            assert_eq!(ballot.total_votes_cast(), 0);
+
+            // a vote delegated to a blank voter is blank as well
+            set_caller(accounts.charlie);
+            ballot.delegate(accounts.bob).unwrap();
+            assert_eq!(ballot.blank_vote_count(), 2);
+            assert_eq!(ballot.redelegate(accounts.django), Err(Error::DelegateAlreadyVoted));
+            assert_eq!(ballot.blank_vote_count(), 2);
         }
 
         #[ink::test]
-        fn delegation_works() {
+        fn rename_proposal_works() {
             let mut ballot = Ballot::default();
-            let to_id = AccountId::from([0x0; 32]);
+            ballot.add_proposal(String::from("Propsal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
 
-            ballot.add_voter(to_id);     
-            ballot.delegate(to_id);
+            assert_eq!(ballot.rename_proposal(0, String::from("Proposal #2")), Err(Error::DuplicateProposal));
+            assert_eq!(ballot.rename_proposal(0, String::new()), Err(Error::ProposalNameInvalid));
+            assert_eq!(ballot.rename_proposal(2, String::from("Proposal #3")), Err(Error::ProposalIndexOutOfBounds));
+            assert_eq!(ballot.rename_proposal(0, String::from("Proposal #1")), Ok(()));
+            assert_eq!(ballot.get_proposal_name_at_index(0), &String::from("Proposal #1"));
 
-            let voter = ballot.get_voter(ballot.get_chairperson()).unwrap();
-            assert_eq!(voter.delegate.unwrap(),to_id);
-        } 
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events.last().unwrap().data[..])
+                .expect("encountered invalid contract event data buffer");
+            match decoded {
+                Event::ProposalRenamed(ProposalRenamed { index, old_name, new_name }) => {
+                    assert_eq!(index, 0);
+                    assert_eq!(old_name, String::from("Propsal #1"));
+                    assert_eq!(new_name, String::from("Proposal #1"));
+                }
+                _ => panic!("encountered unexpected event kind: expected a ProposalRenamed event"),
+            }
+
+            ballot.vote(1).unwrap();
+            assert_eq!(ballot.rename_proposal(1, String::from("Proposal #3")), Err(Error::ProposalHasVotes));
+        }
 
         #[ink::test]
-        fn get_winning_proposal_name_working() {
+        fn final_result_works() {
+            let mut ballot = Ballot::new(None, None, None, 2, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+            assert_eq!(ballot.final_result(), FinalResult {
+                winner_index: None,
+                winner_name: None,
+                winner_votes: 0,
+                quorum_met: false,
+                is_tie: false,
+            });
+
+            ballot.vote(1).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(1).unwrap();
+            set_caller(accounts.charlie);
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.final_result(), FinalResult {
+                winner_index: Some(1),
+                winner_name: Some(String::from("Proposal #2")),
+                winner_votes: 2,
+                quorum_met: true,
+                is_tie: false,
+            });
+        }
+
+        #[ink::test]
+        fn delegated_weight_follows_revoked_vote() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+            set_caller(accounts.alice);
+            ballot.delegate(accounts.bob).unwrap();
+            assert_eq!(ballot.get_proposal_votes(0), Some(2));
+
+            // the delegated vote moves along with bob's vote
+            set_caller(accounts.bob);
+            ballot.change_vote(1).unwrap();
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+            assert_eq!(ballot.get_proposal_votes(1), Some(2));
+            ballot.revoke_vote().unwrap();
+            assert_eq!(ballot.get_proposal_votes(1), Some(0));
+            assert_eq!(ballot.total_votes_cast(), 0);
+
+            set_caller(accounts.alice);
+            ballot.clear_delegation().unwrap();
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+            assert_eq!(ballot.get_proposal_votes(0), Some(0));
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(0), Some(1));
+        }
+
+        #[ink::test]
+        fn voting_right_changes_keep_delegated_votes() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+            ballot.delegate(accounts.bob).unwrap();
+
+            assert_eq!(ballot.revoke_voting_right(accounts.bob), Err(Error::HoldsDelegatedVotes));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(2));
+            ballot.give_weighted_voting_right(accounts.bob, 3).unwrap();
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(4));
+            ballot.give_voting_rights(vec![accounts.bob]).unwrap();
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(2));
+
+            ballot.clear_delegation().unwrap();
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+            assert_eq!(ballot.revoke_voting_right(accounts.bob), Ok(()));
+        }
+
+        #[ink::test]
+        fn reset_ballot_returns_delegated_weight() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+            ballot.delegate(accounts.bob).unwrap();
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+
+            set_caller(accounts.alice);
+            ballot.reset_ballot();
+            assert_eq!(ballot.voting_weight_of(accounts.alice), Some(1));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+            assert_eq!(ballot.get_voter_info(accounts.bob).unwrap().delegated_weight, 0);
+        }
+
+        #[ink::test]
+        fn delegate_fails_after_right_expired() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_right_until(accounts.bob, 0).unwrap();
+            ballot.give_voting_right(accounts.charlie).unwrap();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.delegate(accounts.charlie), Err(Error::NoVotingRight));
+            assert_eq!(ballot.voting_weight_of(accounts.charlie), Some(1));
+            assert_eq!(ballot.has_voted(accounts.bob), Some(false));
+        }
+
+        #[ink::test]
+        fn delegate_respects_snapshot_block() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.set_snapshot_block(0);
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            ballot.add_voter(accounts.charlie).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+
+            // charlie registered after the snapshot
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.delegate(accounts.bob), Err(Error::NoVotingRight));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+        }
+
+        #[ink::test]
+        fn delegation_cap_applies_after_delegate_voted() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.set_max_delegated_weight(2);
+            set_caller(accounts.bob);
+            ballot.vote(0).unwrap();
+
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.delegate(accounts.bob), Ok(()));
+            set_caller(accounts.django);
+            assert_eq!(ballot.delegate(accounts.bob), Err(Error::DelegationCapExceeded));
+            set_caller(accounts.alice);
+            assert_eq!(ballot.delegate(accounts.bob), Err(Error::DelegationCapExceeded));
+            assert_eq!(ballot.get_proposal_votes(0), Some(2));
+        }
+
+        #[ink::test]
+        fn proposals_cannot_shift_under_commitments() {
+            let names = vec![String::from("Proposal #1"), String::from("Proposal #2"), String::from("Proposal #3")];
+            let mut ballot = Ballot::new(Some(names), None, Some(0), 0, DEFAULT_MAX_NAME_LEN, true, 1, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            assert_eq!(ballot.commit_vote(commitment_of(2, 42)), Ok(()));
+            assert_eq!(ballot.remove_proposal(0), Err(Error::CommitmentsPending));
+            assert_eq!(ballot.merge_proposals(1, 0), Err(Error::CommitmentsPending));
+            assert_eq!(ballot.get_proposal_count(), 3);
+
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.reveal_vote(2, 42), Ok(()));
+            assert_eq!(ballot.remove_proposal(0), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(1), Some(1));
+        }
+
+        #[ink::test]
+        fn set_deadline_fails_once_set() {
+            let mut ballot = Ballot::default();
+            assert_eq!(ballot.set_deadline(100), Ok(()));
+            assert_eq!(ballot.set_deadline(0), Err(Error::DeadlineAlreadySet));
+            assert_eq!(ballot.set_deadline(120), Err(Error::DeadlineAlreadySet));
+            assert_eq!(ballot.get_deadline(), Some(100));
+        }
+
+        #[ink::test]
+        fn set_deadline_fails_for_past_block() {
+            let mut ballot = Ballot::default();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+            assert_eq!(ballot.set_deadline(1), Err(Error::DeadlineInPast));
+            assert_eq!(ballot.get_deadline(), None);
+            assert_eq!(ballot.set_deadline(2), Ok(()));
+        }
+
+        #[ink::test]
+        fn inactive_proposal_cannot_be_ranked() {
+            let names = vec![String::from("A"), String::from("B"), String::from("C")];
+            let mut ballot = Ballot::new(Some(names), None, None, 0, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::RankedChoice, TieBreak::LowestIndex, 0, String::new(), String::new());
+            ballot.set_proposal_active(1, false).unwrap();
+
+            assert_eq!(ballot.vote_ranked(vec![1, 0]), Err(Error::ProposalInactive));
+            assert_eq!(ballot.vote_ranked(vec![0, 1]), Err(Error::ProposalInactive));
+            assert_eq!(ballot.vote_ranked(vec![2, 0]), Ok(()));
+            assert_eq!(ballot.get_proposal_votes(1), Some(0));
+        }
+
+        #[ink::test]
+        fn auto_finalize_on_every_vote_path() {
+            let mut ballot = Ballot::new(None, None, None, 2, DEFAULT_MAX_NAME_LEN, true, 0, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie]).unwrap();
+            ballot.set_auto_finalize(true);
+
+            // a write-in vote followed by a delegation to the voter
+            set_caller(accounts.bob);
+            assert_eq!(ballot.propose_and_vote(String::from("Proposal #1")), Ok(0));
+            assert_eq!(ballot.finalized_winner(), None);
+            set_caller(accounts.charlie);
+            ballot.delegate(accounts.bob).unwrap();
+            assert_eq!(ballot.finalized_winner(), Some(0));
+
+            set_caller(accounts.alice);
+            let names = vec![String::from("A"), String::from("B")];
+            let mut ranked = Ballot::new(Some(names), None, None, 1, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::RankedChoice, TieBreak::LowestIndex, 0, String::new(), String::new());
+            ranked.set_auto_finalize(true);
+            ranked.vote_ranked(vec![1, 0]).unwrap();
+            assert_eq!(ranked.finalized_winner(), Some(1));
+        }
+
+        #[ink::test]
+        fn blank_vote_is_a_live_vote() {
+            let mut ballot = Ballot::new(None, None, None, 2, DEFAULT_MAX_NAME_LEN, true, 2, VotingMode::Plurality, TieBreak::LowestIndex, 0, String::new(), String::new());
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right(accounts.bob).unwrap();
+            ballot.set_auto_finalize(true);
+
+            ballot.vote(0).unwrap();
+            assert_eq!(ballot.finalized_winner(), None);
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote_blank(), Ok(()));
+            assert!(ballot.has_quorum());
+            assert_eq!(ballot.finalized_winner(), Some(0));
+
+            let emitted_events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let decoded = <Event as scale::Decode>::decode(&mut &emitted_events[emitted_events.len() - 2].data[..])
+                .expect("encountered invalid contract event data buffer");
+            match decoded {
+                Event::BlankVoteCast(BlankVoteCast { voter, weight }) => {
+                    assert_eq!(voter, accounts.bob);
+                    assert_eq!(weight, 1);
+                }
+                _ => panic!("encountered unexpected event kind: expected a BlankVoteCast event"),
+            }
+
+            // seeding is closed once a blank vote was cast
+            set_caller(accounts.alice);
+            let mut seeded = Ballot::default();
+            seeded.add_proposal(String::from("Proposal #1")).unwrap();
+            seeded.add_proposal(String::from("Proposal #2")).unwrap();
+            seeded.vote_blank().unwrap();
+            assert_eq!(seeded.seed_votes(0, 3), Err(Error::SeedingClosed));
+        }
+
+        #[ink::test]
+        fn clear_delegation_fails_after_delegate_delegated() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django]).unwrap();
+
+            set_caller(accounts.bob);
+            ballot.delegate(accounts.charlie).unwrap();
+            set_caller(accounts.charlie);
+            ballot.delegate(accounts.django).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.clear_delegation(), Err(Error::DelegateAlreadyVoted));
+            set_caller(accounts.charlie);
+            assert_eq!(ballot.clear_delegation(), Ok(()));
+            assert_eq!(ballot.voting_weight_of(accounts.bob), Some(1));
+            assert_eq!(ballot.voting_weight_of(accounts.charlie), Some(2));
+            assert_eq!(ballot.voting_weight_of(accounts.django), Some(1));
+        }
+
+        #[ink::test]
+        fn redelegation_fails_after_delegate_delegated() {
+            let mut ballot = Ballot::default();
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voters(vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve]).unwrap();
+            ballot.give_voting_rights(vec![accounts.bob, accounts.charlie, accounts.django, accounts.eve]).unwrap();
+
+            set_caller(accounts.bob);
+            ballot.delegate(accounts.charlie).unwrap();
+            set_caller(accounts.charlie);
+            ballot.delegate(accounts.django).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(ballot.redelegate(accounts.eve), Err(Error::DelegateAlreadyVoted));
+            assert_eq!(ballot.voting_weight_of(accounts.django), Some(3));
+            assert_eq!(ballot.voting_weight_of(accounts.eve), Some(1));
+            assert_eq!(ballot.get_voter(accounts.bob).unwrap().delegate, Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn proposals_are_frozen_after_finalize() {
+            let mut ballot = Ballot::default();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.vote(1).unwrap();
+            ballot.lock_proposals();
+            ballot.finalize().unwrap();
+
+            assert_eq!(ballot.remove_proposal(0), Err(Error::AlreadyFinalized));
+            assert_eq!(ballot.merge_proposals(1, 0), Err(Error::AlreadyFinalized));
+            assert_eq!(ballot.seed_votes(0, 5), Err(Error::AlreadyFinalized));
+            assert_eq!(ballot.set_proposal_active(1, false), Err(Error::AlreadyFinalized));
+            assert_eq!(ballot.get_proposal_count(), 2);
+            assert_eq!(ballot.finalized_winner(), Some(1));
+        }
+
+        #[ink::test]
+        fn give_voting_rights_clears_expiry() {
             let mut ballot = Ballot::default();
-            ballot.add_proposal(String::from("Proposal #1"));
-            ballot.add_proposal(String::from("Proposal #2"));
-            ballot.vote(0);
-            let proposal_name = ballot.get_winning_proposal_name();
-            assert_eq!(proposal_name, "Proposal #1");
+            let accounts = default_accounts();
+            ballot.add_proposal(String::from("Proposal #1")).unwrap();
+            ballot.add_proposal(String::from("Proposal #2")).unwrap();
+            ballot.add_voter(accounts.bob).unwrap();
+            ballot.give_voting_right_until(accounts.bob, 0).unwrap();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>().unwrap();
+
+            ballot.give_voting_rights(vec![accounts.bob]).unwrap();
+            set_caller(accounts.bob);
+            assert_eq!(ballot.vote(0), Ok(()));
         }
 
     }